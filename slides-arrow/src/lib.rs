@@ -235,6 +235,7 @@ impl GuestModule for Arrows {
         vec![modules::Function {
             name: "arrow".into(),
             args: vec![element, element, options],
+            param_names: vec!["from".into(), "to".into(), "options".into()],
             result_type: void,
         }]
     }
@@ -9,14 +9,30 @@ struct FunctionDefinition {
 }
 
 enum Parameter {
-    Type(String),
+    /// The name, the type and whether the parameter is an `Option`, which
+    /// can be left out.
+    Type(String, String, bool),
     Raw(String),
 }
 
 impl Parameter {
     pub fn as_type(&self) -> Option<&str> {
         match self {
-            Parameter::Type(it) => Some(it),
+            Parameter::Type(_, it, _) => Some(it),
+            _ => None,
+        }
+    }
+
+    pub fn as_name(&self) -> Option<&str> {
+        match self {
+            Parameter::Type(it, _, _) => Some(it),
+            _ => None,
+        }
+    }
+
+    pub fn is_optional(&self) -> Option<bool> {
+        match self {
+            Parameter::Type(_, _, it) => Some(*it),
             _ => None,
         }
     }
@@ -27,11 +43,20 @@ fn main() {
     let dest_path = Path::new(&out_dir).join("global_functions.rs");
     let source_code = fs::read_to_string("src/compiler/evaluator/functions.rs").unwrap();
     let mut functions = Vec::new();
-    for line in source_code.lines() {
+    let mut lines = source_code.lines();
+    while let Some(line) = lines.next() {
         let line = line.trim();
         let Some(line) = line.strip_prefix("pub fn ") else {
             continue;
         };
+        // Long signatures are wrapped by rustfmt, so join them back together.
+        let mut line = line.to_owned();
+        while !line.ends_with('{') {
+            let Some(next) = lines.next() else {
+                break;
+            };
+            line.push_str(next.trim());
+        }
         let Some((name, line)) = line.split_once('(') else {
             continue;
         };
@@ -61,10 +86,18 @@ fn main() {
                     skipped += 1;
                     raw.clone()
                 }
-                Parameter::Type(type_) => {
+                Parameter::Type(_, type_, false) => {
                     let conversion_function = convert_type_name_to_conversion_function(type_);
                     format!("args[{}].value.{conversion_function}.clone()", i - skipped)
                 }
+                // Left out arguments are passed as none by the binder.
+                Parameter::Type(_, type_, true) => {
+                    let conversion_function = convert_type_name_to_conversion_function(type_);
+                    format!(
+                        "match &args[{}].value {{ value::Value::None(_) => None, value => Some(value.{conversion_function}.clone()) }}",
+                        i - skipped
+                    )
+                }
             })
             .collect::<Vec<String>>()
             .join(", ")
@@ -76,7 +109,9 @@ fn main() {
             format!(
                 "    FunctionDefinition {{
         name: {:?},
+        parameter_names: &[{}],
         parameters: &[{}],
+        optional_parameters: &[{}],
         return_type: Type::from_rust_string({:?}).unwrap(),
         call: |_evaluator, mut args| {{
         assert_eq!(args.len(), {});
@@ -84,6 +119,11 @@ fn main() {
         }}
     }}",
                 f.name,
+                f.parameters
+                    .iter()
+                    .filter_map(|p| p.as_name().map(|p| format!("{p:?}")))
+                    .collect::<Vec<String>>()
+                    .join(", "),
                 f.parameters
                     .iter()
                     .filter_map(|p| p
@@ -91,6 +131,11 @@ fn main() {
                         .map(|p| format!("Type::from_rust_string({p:?}).unwrap()")))
                     .collect::<Vec<String>>()
                     .join(", "),
+                f.parameters
+                    .iter()
+                    .filter_map(|p| p.is_optional().map(|p| p.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", "),
                 f.return_type,
                 f.parameters
                     .iter()
@@ -113,7 +158,10 @@ use crate::compiler::evaluator::{{Value, value, Evaluator}};
 
 pub struct FunctionDefinition {{
     pub name: &'static str,
+    pub parameter_names: &'static [&'static str],
     pub parameters: &'static [Type],
+    /// Whether the parameter at the same index can be left out.
+    pub optional_parameters: &'static [bool],
     pub return_type: Type,
     pub call: fn(&mut Evaluator, Vec<Value>) -> value::Value,
 }}
@@ -139,7 +187,16 @@ fn convert_type_name_to_conversion_function(p: &String) -> String {
             "String" | "Background" | "Color" | "Label" | "Image" | "Flex" | "Position" => {
                 p.to_ascii_lowercase()
             }
+            "StyleUnit" => "style_unit".to_owned(),
+            "Angle" => "angle".to_owned(),
             "StringArray" => "string_array".to_owned(),
+            "ColorStop" => "color_stop".to_owned(),
+            "ColorStopArray" => "color_stop_array".to_owned(),
+            "HorizontalAlignment" => "horizontal_alignment".to_owned(),
+            "BackgroundSize" => "background_size".to_owned(),
+            "GridCellSizes" => "grid_cell_sizes".to_owned(),
+            "bool" => "bool".to_owned(),
+            "VerticalAlignment" => "vertical_alignment".to_owned(),
             "Vec<Element>" => "element_array".to_owned(),
             "Element" => return "clone().convert_to_element()".into(),
             _ => unreachable!("Unexpected type {p}"),
@@ -150,6 +207,7 @@ fn convert_type_name_to_conversion_function(p: &String) -> String {
 fn parse_parameters(parameters: &str) -> Vec<Parameter> {
     parameters
         .split(',')
+        .filter(|p| !p.trim().is_empty())
         .map(|p| {
             let p = p.trim();
             let (name, type_) = p.trim().split_once(':').unwrap();
@@ -157,8 +215,13 @@ fn parse_parameters(parameters: &str) -> Vec<Parameter> {
             let type_ = type_.trim();
             if name == "_evaluator" {
                 Parameter::Raw("_evaluator".into())
+            } else if let Some(type_) = type_
+                .strip_prefix("Option<")
+                .and_then(|t| t.strip_suffix('>'))
+            {
+                Parameter::Type(name.into(), type_.into(), true)
             } else {
-                Parameter::Type(type_.into())
+                Parameter::Type(name.into(), type_.into(), false)
             }
         })
         .collect()
@@ -58,6 +58,8 @@ pub fn compile_project(
     file: impl Into<std::path::PathBuf>,
     output: impl Into<std::path::PathBuf>,
     debug: DebugLang,
+    theme: Option<String>,
+    font_cache: Option<PathBuf>,
 ) -> slides_rs_core::Result<CompilationResult> {
     let file = file.into();
     let output = output.into();
@@ -65,7 +67,7 @@ pub fn compile_project(
         diagnostics: Diagnostics::new(),
         used_files: vec![file.clone()],
     };
-    let presentation = match binder::create_presentation_from_file(file, debug) {
+    let presentation = match binder::create_presentation_from_file(file, debug, theme) {
         Ok(it) => it,
         Err(binder::Error::LanguageErrors(diagnostics)) => {
             result.diagnostics = diagnostics;
@@ -81,6 +83,9 @@ pub fn compile_project(
         dbg!(&presentation);
     }
     let mut emitter = PresentationEmitter::new(output)?;
+    if let Some(font_cache) = font_cache {
+        emitter.set_font_cache(font_cache);
+    }
     presentation.output_to_directory(&mut emitter)?;
     result
         .used_files
@@ -1,7 +1,10 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 use convert_case::Casing;
-use slides_rs_core::Presentation;
+use slides_rs_core::{Angle, Presentation, StyleUnit, StyleUnitParseError};
 use string_interner::symbol::SymbolUsize;
 use summum_types::summum;
 use typing::{FunctionType, Type, TypeId, TypeInterner};
@@ -9,6 +12,10 @@ use typing::{FunctionType, Type, TypeId, TypeInterner};
 pub mod globals;
 pub mod typing;
 
+/// The directory next to the presentation, in which themes are looked up by
+/// their name.
+const THEMES_DIRECTORY: &str = "slides-themes";
+
 use super::{
     DebugLang,
     diagnostics::Diagnostics,
@@ -21,7 +28,7 @@ use super::{
     parser::{self, SyntaxNode, SyntaxNodeKind, debug_ast},
 };
 use crate::{
-    Context, Location, ModuleIndex, StringInterner, VariableId,
+    Context, FileId, Location, ModuleIndex, StringInterner, VariableId,
     compiler::{evaluator::value::UserFunctionValue, lexer::Trivia, module},
 };
 use thiserror::Error;
@@ -39,9 +46,11 @@ pub enum Error {
 pub(crate) fn create_presentation_from_file(
     file: PathBuf,
     debug: DebugLang,
+    theme: Option<String>,
 ) -> Result<Presentation, Error> {
     let mut context = Context::new();
     context.debug = debug;
+    context.theme = theme;
     let file = context.load_file(file)?;
     let ast = parser::parse_file(file, &mut context);
     if debug.parser {
@@ -131,6 +140,31 @@ fn debug_bound_node(statement: &BoundNode, context: &Context, indent: String) {
         BoundNodeKind::ImportStatement(path) => {
             println!("Import {}", path.display());
         }
+        BoundNodeKind::ThemeStatement(statements) => {
+            println!("Theme");
+            for statement in statements {
+                debug_bound_node(statement, context, format!("{indent}    "));
+            }
+        }
+        BoundNodeKind::TokensStatement(tokens) => {
+            println!("Tokens");
+            for token in tokens {
+                println!(
+                    "{indent}    Token {}",
+                    context.string_interner.resolve_variable(token.variable)
+                );
+                debug_bound_node(&token.value, context, format!("{indent}        "));
+            }
+        }
+        BoundNodeKind::SectionStatement(title) => {
+            println!("Section {title}");
+        }
+        BoundNodeKind::RemoveStatement(variable) => {
+            println!(
+                "Remove {}",
+                context.string_interner.resolve_variable(*variable)
+            );
+        }
         BoundNodeKind::AssignmentStatement(assignment_statement) => {
             println!("Assignment");
             debug_bound_node(&assignment_statement.lhs, context, format!("{indent}    "));
@@ -204,6 +238,23 @@ fn debug_bound_node(statement: &BoundNode, context: &Context, indent: String) {
                 format!("{indent}    ="),
             );
         }
+        BoundNodeKind::PropertyDeclaration(property_declaration) => {
+            println!(
+                "Property Declaration {}: {}",
+                context
+                    .string_interner
+                    .resolve_variable(property_declaration.variable),
+                context.type_interner.id_to_simple_string(
+                    property_declaration.value.type_,
+                    &context.string_interner
+                )
+            );
+            debug_bound_node(
+                &property_declaration.value,
+                context,
+                format!("{indent}    ="),
+            );
+        }
         BoundNodeKind::Dict(items) => {
             println!("Dict:");
             for (name, entry) in items {
@@ -223,6 +274,12 @@ fn debug_bound_node(statement: &BoundNode, context: &Context, indent: String) {
                 debug_bound_node(entry, context, format!("{indent}    "));
             }
         }
+        BoundNodeKind::Children(body) => {
+            println!("Children:");
+            for statement in body {
+                debug_bound_node(statement, context, format!("{indent}    "));
+            }
+        }
         BoundNodeKind::MemberAccess(member_access) => {
             println!(
                 "Member Access .{}",
@@ -255,6 +312,45 @@ fn debug_bound_node(statement: &BoundNode, context: &Context, indent: String) {
             // TODO: Debug print parameters?
             debug_bound_node(&lambda.body, context, format!("{indent}    "));
         }
+        BoundNodeKind::Match(match_) => {
+            println!(
+                "Match: {}",
+                context
+                    .type_interner
+                    .id_to_simple_string(statement.type_, &context.string_interner)
+            );
+            debug_bound_node(&match_.value, context, format!("{indent}    "));
+            for arm in &match_.arms {
+                debug_bound_node(
+                    &arm.body,
+                    context,
+                    format!("{indent}    {} => ", arm.variant.as_deref().unwrap_or("_")),
+                );
+            }
+        }
+        BoundNodeKind::MatchStatement(match_statement) => {
+            println!("MatchStatement");
+            debug_bound_node(&match_statement.value, context, format!("{indent}    "));
+            for arm in &match_statement.arms {
+                println!("{indent}    {}:", arm.variant.as_deref().unwrap_or("_"));
+                for statement in &arm.body {
+                    debug_bound_node(statement, context, format!("{indent}        "));
+                }
+            }
+        }
+        BoundNodeKind::If(if_) => {
+            println!(
+                "If: {}",
+                context
+                    .type_interner
+                    .id_to_simple_string(statement.type_, &context.string_interner)
+            );
+            debug_bound_node(&if_.condition, context, format!("{indent}    "));
+            debug_bound_node(&if_.body, context, format!("{indent}    then "));
+            if let Some(else_body) = &if_.optional_else {
+                debug_bound_node(else_body, context, format!("{indent}    else "));
+            }
+        }
     }
 }
 
@@ -265,25 +361,40 @@ pub struct Variable {
     pub type_: TypeId,
 }
 
+#[derive(Clone)]
 pub struct Scope {
     variables: HashMap<VariableId, Variable>,
+    /// The parameters of the functions declared in this scope, so that
+    /// arguments can be passed to them by name.
+    function_parameters: HashMap<VariableId, Vec<Parameter>>,
 }
 
 impl Scope {
     pub fn global(string_interner: &mut StringInterner, type_interner: &mut TypeInterner) -> Self {
-        let mut global = Self {
-            variables: HashMap::new(),
-        };
+        let mut global = Self::new();
         let f = globals::FUNCTIONS;
         for function in f {
             let id = string_interner.create_or_get_variable(function.name);
+            let parameters = function
+                .parameter_names
+                .iter()
+                .zip(function.parameters)
+                .zip(function.optional_parameters)
+                .map(|((name, type_), is_optional)| Parameter {
+                    id: string_interner.create_or_get_variable(name),
+                    type_: type_interner.get_or_intern(type_.clone()),
+                    // The builtin picks the default of left out arguments.
+                    value: is_optional.then(Value::none),
+                })
+                .collect::<Vec<_>>();
+            let min_argument_count = parameters.iter().filter(|p| p.value.is_none()).count();
+            global.function_parameters.insert(id, parameters);
             let argument_types: Vec<TypeId> = function
                 .parameters
                 .into_iter()
                 .map(|t| type_interner.get_or_intern(t.clone()))
                 .collect();
             let return_type = type_interner.get_or_intern(function.return_type.clone());
-            let min_argument_count = argument_types.len();
             let type_ = type_interner.get_or_intern(Type::Function(FunctionType {
                 argument_types,
                 min_argument_count,
@@ -310,6 +421,11 @@ impl Scope {
                 .expect("infallible");
         }
 
+        let id = string_interner.create_or_get_variable("slide_count");
+        global
+            .try_register_variable(id, TypeId::INTEGER, Location::zero())
+            .expect("infallible");
+
         // debug_scope("globals", &global, string_interner);
 
         global
@@ -318,6 +434,7 @@ impl Scope {
     pub fn new() -> Self {
         Self {
             variables: HashMap::new(),
+            function_parameters: HashMap::new(),
         }
     }
 
@@ -362,11 +479,39 @@ fn debug_scope(name: &str, scope: &Scope, context: &Context) {
     println!();
 }
 
+/// What a slide inherits from the slide it extends.
+#[derive(Clone)]
+struct SlideBase {
+    scope: Scope,
+    body: Vec<BoundNode>,
+    layout: Option<Token>,
+}
+
 pub struct Binder {
     scopes: Vec<Scope>,
     types: HashMap<SymbolUsize, TypeId>,
     current_expected_type: Vec<TypeId>,
     modules: Vec<Module>,
+    needs_slide_titles: bool,
+    /// Every slide bound so far, so that later slides can extend them.
+    slides: HashMap<VariableId, SlideBase>,
+    /// The name of every slide of the presentation, so that `slide` can check
+    /// constant names before any slide is evaluated.
+    slide_names: HashSet<String>,
+    layouts: HashSet<VariableId>,
+    /// The properties of every user defined element, which can be set in
+    /// stylings for that element.
+    element_properties: HashMap<TypeId, Vec<(VariableId, TypeId)>>,
+    /// The name of the element type of every named styling bound so far, so
+    /// that later stylings for the same element can extend them.
+    stylings: HashMap<VariableId, String>,
+    /// Whether a theme has been bound already.
+    uses_theme: bool,
+    /// The type of every token bound so far, so that a theme can redeclare
+    /// the tokens of the presentation and the other way around.
+    tokens: HashMap<VariableId, TypeId>,
+    /// Index of the outermost scope of the slide which is currently bound.
+    slide_scope: Option<usize>,
 }
 
 impl Binder {
@@ -386,6 +531,15 @@ impl Binder {
                 .collect(),
             current_expected_type: Vec::new(),
             modules: Vec::new(),
+            needs_slide_titles: false,
+            slides: HashMap::new(),
+            slide_names: HashSet::new(),
+            layouts: HashSet::new(),
+            element_properties: HashMap::new(),
+            stylings: HashMap::new(),
+            uses_theme: false,
+            tokens: HashMap::new(),
+            slide_scope: None,
         }
     }
 
@@ -445,6 +599,23 @@ impl Binder {
             .next()
     }
 
+    /// Looks up a variable of the global scope, like a builtin function,
+    /// ignoring any variable which shadows it.
+    fn look_up_global(&self, id: VariableId) -> Option<&Variable> {
+        self.scopes.first().and_then(|s| s.look_up(id))
+    }
+
+    /// Looks up the closest variable which can be called. Members of
+    /// stylings share their names with some builtins, like `font`, and
+    /// should not hide them in function calls.
+    fn look_up_function(&self, id: VariableId, context: &Context) -> Option<&Variable> {
+        self.scopes
+            .iter()
+            .rev()
+            .filter_map(|s| s.look_up(id))
+            .find(|v| matches!(context.type_interner.resolve(v.type_), Type::Function(_)))
+    }
+
     fn look_up_type_by_name(&self, type_name: SymbolUsize) -> Option<TypeId> {
         self.types.get(&type_name).copied()
     }
@@ -457,6 +628,31 @@ impl Binder {
         Some(name)
     }
 
+    /// Registers the parameters of a function declared in the current scope.
+    fn register_function_parameters(&mut self, function: VariableId, parameters: Vec<Parameter>) {
+        self.current_scope_mut()
+            .function_parameters
+            .insert(function, parameters);
+    }
+
+    /// Looks up the parameters of the closest function, so that a variable
+    /// shadowing a function does not get its parameters.
+    fn look_up_function_parameters(
+        &self,
+        function: VariableId,
+        context: &Context,
+    ) -> Option<&Vec<Parameter>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find(|s| {
+                s.look_up(function).is_some_and(|v| {
+                    matches!(context.type_interner.resolve(v.type_), Type::Function(_))
+                })
+            })
+            .and_then(|s| s.function_parameters.get(&function))
+    }
+
     fn push_expected_type(&mut self, type_: TypeId) {
         self.current_expected_type.push(type_);
     }
@@ -488,12 +684,20 @@ pub enum StylingType {
     Label,
     Image,
     Slide,
+    Grid,
+    Flex,
+    CustomElement,
 }
 
 #[derive(Debug, Clone)]
 pub struct StylingStatement {
     pub name: VariableId,
     pub type_: StylingType,
+    /// The type name of the element, if this styles a user defined element.
+    pub custom_element: Option<String>,
+    pub properties: Vec<VariableId>,
+    /// The styling this styling extends.
+    pub base: Option<VariableId>,
     pub body: Vec<BoundNode>,
 }
 
@@ -508,6 +712,9 @@ pub struct ElementStatement {
     pub name: VariableId,
     pub type_: TypeId,
     pub parameters: Vec<Parameter>,
+    /// The parameter, which receives the elements of a trailing body.
+    pub children_parameter: Option<VariableId>,
+    pub properties: Vec<VariableId>,
     pub body: Vec<BoundNode>,
 }
 
@@ -515,6 +722,8 @@ pub struct ElementStatement {
 pub struct TemplateStatement {
     pub name: VariableId,
     pub parameters: Vec<Parameter>,
+    /// The parameter, which receives the elements of a trailing body.
+    pub children_parameter: Option<VariableId>,
     pub body: Vec<BoundNode>,
 }
 
@@ -576,11 +785,22 @@ pub enum BoundBinaryOperator {
     Division,
     And,
     Or,
+    Equals,
+    NotEquals,
+    Coalesce,
     Unknown(SymbolUsize),
 }
 
 impl BoundBinaryOperator {
     fn type_(&self, lhs: TypeId, rhs: TypeId, type_interner: &mut TypeInterner) -> TypeId {
+        if lhs == TypeId::ERROR || rhs == TypeId::ERROR {
+            return TypeId::ERROR;
+        }
+        match self {
+            BoundBinaryOperator::Equals | BoundBinaryOperator::NotEquals => return TypeId::BOOL,
+            BoundBinaryOperator::Coalesce => return rhs,
+            _ => {}
+        }
         match (lhs, rhs) {
             (TypeId::ERROR, _) | (_, TypeId::ERROR) => TypeId::ERROR,
             (TypeId::INTEGER, TypeId::INTEGER) => TypeId::INTEGER,
@@ -610,8 +830,9 @@ impl BoundBinaryOperator {
         }
     }
 
-    pub(crate) fn execute(&self, lhs: Value, rhs: Value) -> Value {
-        match self {
+    /// Returns `None` if an integer division divides by zero or overflows.
+    pub(crate) fn execute(&self, lhs: Value, rhs: Value) -> Option<Value> {
+        Some(match self {
             BoundBinaryOperator::Addition => match (lhs, rhs) {
                 (Value::Integer(lhs), Value::Integer(rhs)) => (lhs + rhs).into(),
                 (Value::Float(lhs), Value::Float(rhs)) => (lhs + rhs).into(),
@@ -623,7 +844,14 @@ impl BoundBinaryOperator {
                 (lhs, Value::String(rhs)) => ([lhs.convert_to_string(), rhs].join("")).into(),
                 _ => unreachable!(),
             },
-            BoundBinaryOperator::Subtraction => (lhs.into_integer() - rhs.into_integer()).into(),
+            BoundBinaryOperator::Subtraction => match (lhs, rhs) {
+                (Value::Integer(lhs), Value::Integer(rhs)) => (lhs - rhs).into(),
+                (Value::Float(lhs), Value::Float(rhs)) => (lhs - rhs).into(),
+                (Value::Integer(lhs), Value::Float(rhs)) => ((lhs as f64) - rhs).into(),
+                (Value::Float(lhs), Value::Integer(rhs)) => (lhs - (rhs as f64)).into(),
+                (Value::StyleUnit(lhs), Value::StyleUnit(rhs)) => (lhs - rhs).into(),
+                _ => unreachable!(),
+            },
             BoundBinaryOperator::Multiplication => match (lhs, rhs) {
                 (Value::Integer(lhs), Value::Integer(rhs)) => (lhs * rhs).into(),
                 (Value::Float(lhs), Value::Float(rhs)) => (lhs * rhs).into(),
@@ -635,7 +863,17 @@ impl BoundBinaryOperator {
                 (Value::Float(lhs), Value::StyleUnit(rhs)) => (rhs * lhs).into(),
                 _ => unreachable!(),
             },
-            BoundBinaryOperator::Division => todo!(),
+            BoundBinaryOperator::Division => match (lhs, rhs) {
+                (Value::Integer(lhs), Value::Integer(rhs)) => {
+                    return lhs.checked_div(rhs).map(Into::into);
+                }
+                (Value::Float(lhs), Value::Float(rhs)) => (lhs / rhs).into(),
+                (Value::Integer(lhs), Value::Float(rhs)) => ((lhs as f64) / rhs).into(),
+                (Value::Float(lhs), Value::Integer(rhs)) => (lhs / (rhs as f64)).into(),
+                (Value::StyleUnit(lhs), Value::Integer(rhs)) => (lhs / rhs as f64).into(),
+                (Value::StyleUnit(lhs), Value::Float(rhs)) => (lhs / rhs).into(),
+                _ => unreachable!(),
+            },
             BoundBinaryOperator::And => todo!(),
             BoundBinaryOperator::Or => match (lhs, rhs) {
                 (Value::Dict(lhs), Value::Dict(rhs)) => {
@@ -645,8 +883,17 @@ impl BoundBinaryOperator {
                 }
                 _ => todo!(),
             },
+            BoundBinaryOperator::Equals => lhs.equals(&rhs).into(),
+            BoundBinaryOperator::NotEquals => (!lhs.equals(&rhs)).into(),
+            BoundBinaryOperator::Coalesce => {
+                if lhs.is_none() {
+                    rhs
+                } else {
+                    lhs
+                }
+            }
             BoundBinaryOperator::Unknown(_symbol_usize) => unreachable!(),
-        }
+        })
     }
 }
 
@@ -663,6 +910,40 @@ pub struct Lambda {
     pub body: Box<BoundNode>,
 }
 
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub variant: Option<String>,
+    pub body: BoundNode,
+}
+
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub value: Box<BoundNode>,
+    pub arms: Vec<MatchArm>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchStatementArm {
+    pub variant: Option<String>,
+    pub body: Vec<BoundNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchStatement {
+    pub value: Box<BoundNode>,
+    pub arms: Vec<MatchStatementArm>,
+}
+
+#[derive(Debug, Clone)]
+pub struct If {
+    /// Set for `if let name = value:`, the value is bound to this variable
+    /// inside the body, if it is not none.
+    pub optional_binding: Option<VariableId>,
+    pub condition: Box<BoundNode>,
+    pub body: Box<BoundNode>,
+    pub optional_else: Option<Box<BoundNode>>,
+}
+
 #[derive(Debug, Clone)]
 
 pub struct BoundError;
@@ -677,6 +958,10 @@ pub enum BoundNodeKind {
     ElementStatement(ElementStatement),
     TemplateStatement(TemplateStatement),
     ImportStatement(PathBuf),
+    ThemeStatement(Vec<BoundNode>),
+    TokensStatement(Vec<VariableDeclaration>),
+    SectionStatement(String),
+    RemoveStatement(VariableId),
     ArrayAccess(ArrayAccess),
     FunctionCall(FunctionCall),
     ReturnStatement(Box<BoundNode>),
@@ -685,13 +970,18 @@ pub enum BoundNodeKind {
     SlideStatement(SlideStatement),
     GlobalStatement(GlobalStatement),
     VariableDeclaration(VariableDeclaration),
+    PropertyDeclaration(VariableDeclaration),
     Dict(Vec<(VariableId, BoundNode)>),
     Array(Vec<BoundNode>),
+    Children(Vec<BoundNode>),
     MemberAccess(MemberAccess),
     Conversion(Conversion),
     PostInitialization(PostInitialization),
     Binary(Binary),
     Lambda(Lambda),
+    Match(Match),
+    MatchStatement(MatchStatement),
+    If(If),
 }
 }
 #[derive(Debug, Clone)]
@@ -729,12 +1019,22 @@ impl BoundNode {
         location: Location,
         name: VariableId,
         type_: StylingType,
+        custom_element: Option<String>,
+        properties: Vec<VariableId>,
+        extends: Option<VariableId>,
         body: Vec<BoundNode>,
     ) -> BoundNode {
         BoundNode {
             base: Some(SyntaxNodeKind::StylingStatement(base)),
             location,
-            kind: BoundNodeKind::StylingStatement(StylingStatement { name, type_, body }),
+            kind: BoundNodeKind::StylingStatement(StylingStatement {
+                name,
+                type_,
+                custom_element,
+                properties,
+                base: extends,
+                body,
+            }),
             type_: TypeId::VOID,
             constant_value: None,
         }
@@ -804,6 +1104,16 @@ impl BoundNode {
         }
     }
 
+    fn fake_variable_reference(variable: &Variable, location: Location) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::VariableReference(variable.clone()),
+            type_: variable.type_,
+            constant_value: None,
+        }
+    }
+
     pub fn fake_literal(value: Value) -> BoundNode {
         BoundNode {
             base: None,
@@ -870,6 +1180,16 @@ impl BoundNode {
         }
     }
 
+    fn property_declaration(variable_declaration: BoundNode) -> BoundNode {
+        match variable_declaration.kind {
+            BoundNodeKind::VariableDeclaration(it) => BoundNode {
+                kind: BoundNodeKind::PropertyDeclaration(it),
+                ..variable_declaration
+            },
+            _ => variable_declaration,
+        }
+    }
+
     fn dict(location: Location, entries: Vec<(VariableId, BoundNode)>, type_: TypeId) -> BoundNode {
         BoundNode {
             base: None,
@@ -933,6 +1253,8 @@ impl BoundNode {
         location: Location,
         element_type: TypeId,
         parameters: Vec<Parameter>,
+        children_parameter: Option<VariableId>,
+        properties: Vec<VariableId>,
         function_type: TypeId,
         name: VariableId,
         body: Vec<BoundNode>,
@@ -943,6 +1265,8 @@ impl BoundNode {
             kind: BoundNodeKind::ElementStatement(ElementStatement {
                 type_: element_type,
                 parameters,
+                children_parameter,
+                properties,
                 name,
                 body,
             }),
@@ -954,6 +1278,7 @@ impl BoundNode {
     fn template_statement(
         location: Location,
         parameters: Vec<Parameter>,
+        children_parameter: Option<VariableId>,
         function_type: TypeId,
         name: VariableId,
         body: Vec<BoundNode>,
@@ -963,6 +1288,7 @@ impl BoundNode {
             location,
             kind: BoundNodeKind::TemplateStatement(TemplateStatement {
                 parameters,
+                children_parameter,
                 name,
                 body,
             }),
@@ -981,6 +1307,46 @@ impl BoundNode {
         }
     }
 
+    fn theme_statement(statements: Vec<BoundNode>, location: Location) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::ThemeStatement(statements),
+            constant_value: None,
+            type_: TypeId::VOID,
+        }
+    }
+
+    fn tokens_statement(tokens: Vec<VariableDeclaration>, location: Location) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::TokensStatement(tokens),
+            constant_value: None,
+            type_: TypeId::VOID,
+        }
+    }
+
+    fn section_statement(title: String, location: Location) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::SectionStatement(title),
+            constant_value: None,
+            type_: TypeId::VOID,
+        }
+    }
+
+    fn remove_statement(variable: VariableId, location: Location) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::RemoveStatement(variable),
+            constant_value: None,
+            type_: TypeId::VOID,
+        }
+    }
+
     fn array(entries: Vec<BoundNode>, location: Location, type_: TypeId) -> BoundNode {
         BoundNode {
             base: None,
@@ -991,6 +1357,16 @@ impl BoundNode {
         }
     }
 
+    fn children(body: Vec<BoundNode>, location: Location, type_: TypeId) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::Children(body),
+            type_,
+            constant_value: None,
+        }
+    }
+
     fn binary(
         location: Location,
         lhs: BoundNode,
@@ -1057,6 +1433,63 @@ impl BoundNode {
         }
     }
 
+    fn match_(
+        location: Location,
+        value: BoundNode,
+        arms: Vec<MatchArm>,
+        type_: TypeId,
+    ) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::Match(Match {
+                value: Box::new(value),
+                arms,
+            }),
+            type_,
+            constant_value: None,
+        }
+    }
+
+    fn match_statement(
+        location: Location,
+        value: BoundNode,
+        arms: Vec<MatchStatementArm>,
+    ) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::MatchStatement(MatchStatement {
+                value: Box::new(value),
+                arms,
+            }),
+            type_: TypeId::VOID,
+            constant_value: None,
+        }
+    }
+
+    fn if_(
+        location: Location,
+        optional_binding: Option<VariableId>,
+        condition: BoundNode,
+        body: BoundNode,
+        optional_else: Option<BoundNode>,
+        type_: TypeId,
+    ) -> BoundNode {
+        BoundNode {
+            base: None,
+            location,
+            kind: BoundNodeKind::If(If {
+                optional_binding,
+                condition: Box::new(condition),
+                body: Box::new(body),
+                optional_else: optional_else.map(Box::new),
+            }),
+            type_,
+            constant_value: None,
+        }
+    }
+
     fn return_statement(value: BoundNode) -> BoundNode {
         BoundNode {
             base: None,
@@ -1088,18 +1521,43 @@ fn constant_conversion(value: Value, target: TypeId, _kind: ConversionKind) -> O
 
 pub struct BoundAst {
     pub statements: Vec<BoundNode>,
+    /// Set if `slide("name")` or `toc()` is used, which need slide titles
+    /// that are only known after all slides have been evaluated once.
+    pub needs_slide_titles: bool,
 }
 
 fn bind_ast(ast: parser::Ast, context: &mut Context) -> BoundAst {
     let mut binder = Binder::new(context);
     let mut statements = Vec::with_capacity(ast.statements.len());
+    let has_use_statement = ast
+        .statements
+        .iter()
+        .any(|s| matches!(s.kind, SyntaxNodeKind::UseStatement(_)));
+    if let Some(theme) = context.theme.clone()
+        && !has_use_statement
+    {
+        statements.push(bind_theme(&theme, Location::zero(), &mut binder, context));
+    }
+    binder.slide_names = ast
+        .statements
+        .iter()
+        .filter_map(|s| match &s.kind {
+            SyntaxNodeKind::SlideStatement(slide_statement) => {
+                Some(slide_statement.name.text(&context.loaded_files).to_owned())
+            }
+            _ => None,
+        })
+        .collect();
     for statement in ast.statements {
         statements.push(bind_node(statement, &mut binder, context));
     }
     if context.debug.types {
         context.type_interner.debug_types(&context.string_interner);
     }
-    BoundAst { statements }
+    BoundAst {
+        statements,
+        needs_slide_titles: binder.needs_slide_titles,
+    }
 }
 
 fn bind_node(statement: SyntaxNode, binder: &mut Binder, context: &mut Context) -> BoundNode {
@@ -1114,6 +1572,11 @@ fn bind_node(statement: SyntaxNode, binder: &mut Binder, context: &mut Context)
         SyntaxNodeKind::SlideStatement(slide_statement) => {
             bind_slide_statement(slide_statement, statement.location, binder, context)
         }
+        SyntaxNodeKind::GlobalStatement(global_statement)
+            if global_statement.global_keyword.kind == TokenKind::TokensKeyword =>
+        {
+            bind_tokens_statement(global_statement, statement.location, binder, context)
+        }
         SyntaxNodeKind::GlobalStatement(global_statement) => {
             bind_global_statement(global_statement, statement.location, binder, context)
         }
@@ -1126,17 +1589,39 @@ fn bind_node(statement: SyntaxNode, binder: &mut Binder, context: &mut Context)
         SyntaxNodeKind::ImportStatement(import_statement) => {
             bind_import_statement(import_statement, statement.location, binder, context)
         }
+        SyntaxNodeKind::UseStatement(use_statement) => {
+            bind_use_statement(use_statement, statement.location, binder, context)
+        }
+        SyntaxNodeKind::EnumStatement(enum_statement) => {
+            bind_enum_statement(enum_statement, binder, context)
+        }
+        SyntaxNodeKind::SectionStatement(section_statement) => {
+            bind_section_statement(section_statement, statement.location, context)
+        }
+        SyntaxNodeKind::RemoveStatement(remove_statement) => {
+            bind_remove_statement(remove_statement, statement.location, binder, context)
+        }
+        SyntaxNodeKind::Match(match_) => bind_match(match_, statement.location, binder, context),
+        SyntaxNodeKind::MatchStatement(match_statement) => {
+            bind_match_statement(match_statement, statement.location, binder, context)
+        }
+        SyntaxNodeKind::If(if_) => bind_if(if_, statement.location, binder, context),
         SyntaxNodeKind::ExpressionStatement(expression_statement) => {
             let mut result = bind_node(*expression_statement.expression, binder, context);
             result.type_ = TypeId::VOID;
             result
         }
         SyntaxNodeKind::VariableDeclaration(variable_declaration) => {
+            if variable_declaration.let_keyword.kind == TokenKind::PropKeyword {
+                context
+                    .diagnostics
+                    .report_property_outside_of_element(variable_declaration.let_keyword.location);
+            }
             bind_variable_declaration(variable_declaration, statement.location, binder, context)
         }
         SyntaxNodeKind::VariableReference(token) => bind_variable_reference(token, binder, context),
         SyntaxNodeKind::Literal(token) => bind_literal(token, binder, context),
-        SyntaxNodeKind::FormatString(token) => bind_string(token, binder, context),
+        SyntaxNodeKind::FormatString(token) => bind_string(token, true, binder, context),
         SyntaxNodeKind::MemberAccess(member_access) => {
             bind_member_access(member_access, statement.location, binder, context)
         }
@@ -1155,6 +1640,9 @@ fn bind_node(statement: SyntaxNode, binder: &mut Binder, context: &mut Context)
         SyntaxNodeKind::Error(consumed) => BoundNode::syntax_error(statement.location, consumed),
         SyntaxNodeKind::Dict(dict) => bind_dict(dict, statement.location, binder, context),
         SyntaxNodeKind::Array(array) => bind_array(array, statement.location, binder, context),
+        SyntaxNodeKind::Children(children) => {
+            bind_children(children, statement.location, binder, context)
+        }
         SyntaxNodeKind::PostInitialization(post_initialization) => {
             bind_post_initialization(post_initialization, statement.location, binder, context)
         }
@@ -1178,28 +1666,42 @@ fn bind_lambda(
     binder: &mut Binder,
     context: &mut Context,
 ) -> BoundNode {
-    // let Some(expected_type) = binder.currently_expected_type() else {
-    //     // Needs more type information!
-    //     return BoundNode::error(location);
-    // };
-    // let Type::Function(function_type) = context.type_interner.resolve(expected_type) else {
-    //     // Must be a function, otherwise this makes zero sense.
-    //     return BoundNode::error(location);
-    // };
-
-    // dbg!(function_type);
-    // BoundNode::function_definition()
+    let expected_type = binder
+        .currently_expected_type()
+        .and_then(|t| context.type_interner.resolve(t).try_as_function_ref())
+        .cloned();
     binder.create_scope();
-    let SyntaxNodeKind::ParameterBlock(parameters) = lambda.parameter.kind else {
-        panic!("Unreachable!");
+    let parameter_nodes = match lambda.parameter.kind {
+        SyntaxNodeKind::ParameterBlock(parameter_block) => parameter_block
+            .parameters
+            .into_iter()
+            .map(|(parameter, _)| parameter)
+            .collect(),
+        SyntaxNodeKind::Parameter(_) => vec![*lambda.parameter],
+        _ => unreachable!("Parser only creates parameters for lambdas"),
     };
-    let parameters = bind_parameter_block(parameters, location, binder, context);
-    // for parameter in parameters {
-    //     binder.expect_register_variable_id(parameter.id, type_, location, context);
-    // }
-    binder.push_expected_type(TypeId::ERROR);
+    let mut parameters = Vec::with_capacity(parameter_nodes.len());
+    for (index, parameter) in parameter_nodes.into_iter().enumerate() {
+        let location = parameter.location;
+        let Some(parameter) = parameter.kind.try_as_parameter() else {
+            continue;
+        };
+        let inferred_type = expected_type
+            .as_ref()
+            .and_then(|f| f.argument_types.get(index).copied());
+        parameters.push(bind_parameter(
+            parameter,
+            location,
+            inferred_type,
+            binder,
+            context,
+        ));
+    }
+    let return_type = expected_type.map_or(TypeId::ERROR, |f| f.return_type);
+    binder.push_expected_type(return_type);
     let body = bind_node(*lambda.body, binder, context);
     binder.drop_expected_type();
+    let body = bind_conversion(body, return_type, ConversionKind::Implicit, binder, context);
     let type_ = Type::Function(FunctionType {
         min_argument_count: parameters.len(),
         argument_types: parameters
@@ -1284,15 +1786,38 @@ fn bind_binary_operator(
         "/" => BoundBinaryOperator::Division,
         "&" => BoundBinaryOperator::And,
         "|" => BoundBinaryOperator::Or,
+        "==" => BoundBinaryOperator::Equals,
+        "!=" => BoundBinaryOperator::NotEquals,
+        "??" => BoundBinaryOperator::Coalesce,
         unknown => BoundBinaryOperator::Unknown(context.string_interner.create_or_get(unknown)),
     };
+    let comparison_types = match operator {
+        BoundBinaryOperator::Equals | BoundBinaryOperator::NotEquals => {
+            comparison_types(lhs.type_, rhs.type_, &context.type_interner)
+        }
+        _ => None,
+    };
     let [lhs_type, rhs_type] = context.type_interner.resolve_types([lhs.type_, rhs.type_]);
     let (lhs_type, rhs_type) = match (lhs_type, operator, rhs_type) {
         (Type::Error, _, _) | (_, _, Type::Error) => (TypeId::ERROR, TypeId::ERROR),
+        (_, BoundBinaryOperator::Equals | BoundBinaryOperator::NotEquals, _)
+            if comparison_types.is_some() =>
+        {
+            comparison_types.unwrap()
+        }
+        (Type::Optional(_), BoundBinaryOperator::Coalesce, Type::Optional(_) | Type::None) => {
+            (lhs.type_, lhs.type_)
+        }
+        (Type::Optional(inner), BoundBinaryOperator::Coalesce, _) => (lhs.type_, *inner),
         (Type::Integer, BoundBinaryOperator::Addition, Type::Integer) => {
             (TypeId::INTEGER, TypeId::INTEGER)
         }
         (Type::Float, BoundBinaryOperator::Addition, Type::Float) => (TypeId::FLOAT, TypeId::FLOAT),
+        (
+            Type::StyleUnit,
+            BoundBinaryOperator::Addition | BoundBinaryOperator::Subtraction,
+            Type::StyleUnit,
+        ) => (TypeId::STYLE_UNIT, TypeId::STYLE_UNIT),
         (Type::String, BoundBinaryOperator::Addition, _) => (TypeId::STRING, TypeId::STRING),
         (_, BoundBinaryOperator::Addition, Type::String) => (TypeId::STRING, TypeId::STRING),
         (Type::Integer, BoundBinaryOperator::Subtraction, Type::Integer) => {
@@ -1304,11 +1829,9 @@ fn bind_binary_operator(
         (Type::Integer, BoundBinaryOperator::Multiplication, Type::Integer) => {
             (TypeId::INTEGER, TypeId::INTEGER)
         }
-        (
-            Type::Float,
-            BoundBinaryOperator::Multiplication | BoundBinaryOperator::Division,
-            Type::StyleUnit,
-        ) => (TypeId::FLOAT, TypeId::STYLE_UNIT),
+        (Type::Float, BoundBinaryOperator::Multiplication, Type::StyleUnit) => {
+            (TypeId::FLOAT, TypeId::STYLE_UNIT)
+        }
         (
             Type::StyleUnit,
             BoundBinaryOperator::Multiplication | BoundBinaryOperator::Division,
@@ -1339,23 +1862,65 @@ fn bind_binary_operator(
             (TypeId::ERROR, TypeId::ERROR)
         }
     };
+    if matches!(operator, BoundBinaryOperator::Division)
+        && matches!(rhs.constant_value, Some(Value::Integer(0)))
+    {
+        context.diagnostics.report_division_by_zero(rhs.location);
+    }
     let lhs = bind_conversion(lhs, lhs_type, ConversionKind::Implicit, binder, context);
     let rhs = bind_conversion(rhs, rhs_type, ConversionKind::Implicit, binder, context);
     (lhs, operator, rhs)
 }
 
-fn bind_array(
-    array: parser::Array,
-    location: Location,
-    binder: &mut Binder,
-    context: &mut Context,
-) -> BoundNode {
-    let mut entries = Vec::with_capacity(array.entries.len());
-    let mut inner_type = TypeId::ERROR;
-    if let Some(type_) = binder.currently_expected_type() {
-        let type_ = context.type_interner.resolve(type_);
+fn comparison_types(
+    lhs: TypeId,
+    rhs: TypeId,
+    type_interner: &TypeInterner,
+) -> Option<(TypeId, TypeId)> {
+    let [lhs_type, rhs_type] = type_interner.resolve_types([lhs, rhs]);
+    let is_comparable = |type_: &Type| {
+        matches!(
+            type_,
+            Type::None
+                | Type::Bool
+                | Type::Integer
+                | Type::Float
+                | Type::String
+                | Type::Path
+                | Type::Enum(_)
+                | Type::Optional(_)
+        )
+    };
+    if !is_comparable(lhs_type) || !is_comparable(rhs_type) {
+        return None;
+    }
+    match (lhs_type, rhs_type) {
+        _ if lhs == rhs => Some((lhs, rhs)),
+        (Type::None, Type::Optional(_)) | (Type::Optional(_), Type::None) => Some((lhs, rhs)),
+        (Type::Integer, Type::Float) | (Type::Float, Type::Integer) => {
+            Some((TypeId::FLOAT, TypeId::FLOAT))
+        }
+        (Type::Optional(inner), _) if *inner == rhs => Some((lhs, lhs)),
+        (_, Type::Optional(inner)) if *inner == lhs => Some((rhs, rhs)),
+        _ => None,
+    }
+}
+
+fn bind_array(
+    array: parser::Array,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let mut entries = Vec::with_capacity(array.entries.len());
+    let mut inner_type = TypeId::ERROR;
+    if let Some(expected_type) = binder.currently_expected_type() {
+        let type_ = context.type_interner.resolve(expected_type);
         if let Some(type_) = type_.try_as_array_ref() {
             inner_type = *type_;
+        } else if matches!(type_, Type::Filter | Type::Transform) {
+            // An array of filters or transforms is converted to a chain.
+            inner_type = expected_type;
         } else {
             context.diagnostics.report_cannot_convert(
                 &context.type_interner,
@@ -1439,6 +2004,508 @@ fn bind_import_statement(
     }
 }
 
+fn bind_use_statement(
+    use_statement: parser::UseStatement,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let kind = use_statement.kind.text(&context.loaded_files);
+    if kind != "theme" {
+        context
+            .diagnostics
+            .report_unknown_use(use_statement.kind.location, kind);
+        return BoundNode::error(location);
+    }
+    if use_statement.name.location.length == 0 {
+        return BoundNode::error(location);
+    }
+    // A theme selected when building the presentation takes precedence.
+    let theme = context.theme.clone().unwrap_or_else(|| {
+        let name = use_statement.name.text(&context.loaded_files);
+        Value::parse_string_literal(name, true, true).into_string()
+    });
+    bind_theme(&theme, location, binder, context)
+}
+
+/// Binds the statements of the theme file `name` in the themes directory as if
+/// they were part of the presentation. The stylings of the theme are applied
+/// after the default stylings of the presentation, see [`Presentation`].
+fn bind_theme(
+    name: &str,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    if binder.uses_theme {
+        context.diagnostics.report_theme_already_used(location);
+        return BoundNode::error(location);
+    }
+    binder.uses_theme = true;
+    // Themes are only looked up by their name, never by a path.
+    if name.is_empty() || name.contains(['/', '\\']) || name == "." || name == ".." {
+        context
+            .diagnostics
+            .report_invalid_theme_name(location, name);
+        return BoundNode::error(location);
+    }
+    let presentation = &context.loaded_files[FileId::ZERO].name;
+    let path = presentation
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(THEMES_DIRECTORY)
+        .join(format!("{name}.sld"));
+    let Ok(file) = context.load_file(path.clone()) else {
+        context
+            .diagnostics
+            .report_unknown_theme(location, name, &path);
+        return BoundNode::error(location);
+    };
+    context.presentation.write().unwrap().add_used_file(path);
+    let ast = parser::parse_file(file, context);
+    let mut statements = Vec::with_capacity(ast.statements.len());
+    for statement in ast.statements {
+        if matches!(
+            statement.kind,
+            SyntaxNodeKind::SlideStatement(_) | SyntaxNodeKind::SectionStatement(_)
+        ) {
+            context
+                .diagnostics
+                .report_slide_in_theme(statement.location);
+            continue;
+        }
+        statements.push(bind_node(statement, binder, context));
+    }
+    BoundNode::theme_statement(statements, location)
+}
+
+fn bind_section_statement(
+    section_statement: parser::SectionStatement,
+    location: Location,
+    context: &mut Context,
+) -> BoundNode {
+    if section_statement.title.location.length == 0 {
+        return BoundNode::error(location);
+    }
+    let title = section_statement.title.text(&context.loaded_files);
+    let title = Value::parse_string_literal(title, true, true).into_string();
+    BoundNode::section_statement(title, location)
+}
+
+/// Binds a `let` of a slide, which redeclares a variable of the slide it
+/// extends. The declaration replaces the original one, so elements built from
+/// it pick up the new value. Therefore it may only use variables declared
+/// before the original declaration and has to keep its type.
+fn bind_overriding_declaration(
+    statement: SyntaxNode,
+    expected_type: TypeId,
+    later_declarations: &[VariableId],
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let slide_scope = binder.slide_scope.expect("only used in slides");
+    let hidden: Vec<Variable> = later_declarations
+        .iter()
+        .filter_map(|id| binder.scopes[slide_scope].variables.remove(id))
+        .collect();
+    // Variables declared by this slide itself are not known yet either.
+    let own_scope = std::mem::replace(binder.current_scope_mut(), Scope::new());
+    let result = bind_node(statement, binder, context);
+    let declared = std::mem::replace(binder.current_scope_mut(), own_scope);
+    binder
+        .current_scope_mut()
+        .variables
+        .extend(declared.variables);
+    for variable in hidden {
+        binder.scopes[slide_scope]
+            .variables
+            .insert(variable.id, variable);
+    }
+    if let BoundNodeKind::VariableDeclaration(variable_declaration) = &result.kind
+        && variable_declaration.value.type_ != expected_type
+        && variable_declaration.value.type_ != TypeId::ERROR
+    {
+        context.diagnostics.report_cannot_convert(
+            &context.type_interner,
+            &context.string_interner,
+            context
+                .type_interner
+                .resolve(variable_declaration.value.type_),
+            context.type_interner.resolve(expected_type),
+            variable_declaration.value.location,
+        );
+    }
+    result
+}
+
+/// Removes an element of the current slide, which is mostly useful for slides
+/// extending another slide.
+fn bind_remove_statement(
+    remove_statement: parser::RemoveStatement,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let name = remove_statement.name.text(&context.loaded_files);
+    let Some(slide_scope) = binder.slide_scope else {
+        context.diagnostics.report_remove_outside_of_slide(location);
+        return BoundNode::error(location);
+    };
+    let id = context.string_interner.create_or_get_variable(name);
+    let Some(scope) = binder.scopes[slide_scope..]
+        .iter_mut()
+        .rev()
+        .find(|scope| scope.look_up(id).is_some())
+    else {
+        context
+            .diagnostics
+            .report_unknown_variable(remove_statement.name.location, name);
+        return BoundNode::error(location);
+    };
+    scope.variables.remove(&id);
+    BoundNode::remove_statement(id, location)
+}
+
+fn bind_enum_statement(
+    enum_statement: parser::EnumStatement,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let name = enum_statement.name.text(&context.loaded_files).to_owned();
+    let mut variants: Vec<String> = Vec::with_capacity(enum_statement.variants.len());
+    for (variant, _) in enum_statement.variants {
+        let variant_name = variant.text(&context.loaded_files);
+        if variants.iter().any(|v| v == variant_name) {
+            context.diagnostics.report_duplicate_enum_variant(
+                variant.location,
+                &name,
+                variant_name,
+            );
+            continue;
+        }
+        variants.push(variant_name.into());
+    }
+
+    let enum_type = context
+        .type_interner
+        .get_or_intern(Type::Enum(name.clone()));
+    let name_symbol = context.string_interner.create_or_get(&name);
+    if binder
+        .register_type_by_name(enum_type, name_symbol)
+        .is_none()
+    {
+        context
+            .diagnostics
+            .report_redeclaration_of_type(enum_statement.name.location, &name);
+        return BoundNode::error(enum_statement.name.location);
+    }
+
+    let definition_type = context
+        .type_interner
+        .get_or_intern(Type::EnumDefinition(Box::new(Type::Enum(name)), variants));
+    if binder
+        .expect_register_variable_token(
+            enum_statement.name,
+            definition_type,
+            enum_statement.name.location,
+            context,
+        )
+        .is_none()
+    {
+        return BoundNode::error(enum_statement.name.location);
+    }
+    BoundNode::empty()
+}
+
+fn bind_match(
+    match_: parser::Match,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let (value, variants) = bind_match_value(*match_.value, binder, context);
+
+    let mut arms: Vec<MatchArm> = Vec::with_capacity(match_.arms.len());
+    let mut matched = Vec::with_capacity(match_.arms.len());
+    let mut type_ = None;
+    for (arm, _) in match_.arms {
+        let SyntaxNodeKind::MatchArm(arm) = arm.kind else {
+            bind_node(arm, binder, context);
+            continue;
+        };
+        let variant = bind_match_arm_variant(arm.variant, &value, &variants, &matched, context);
+        matched.push(variant.clone());
+        let mut body = bind_node(*arm.body, binder, context);
+        match type_ {
+            Some(type_) => {
+                body = bind_conversion(body, type_, ConversionKind::Implicit, binder, context)
+            }
+            None => type_ = Some(body.type_),
+        }
+        arms.push(MatchArm { variant, body });
+    }
+    check_match_is_exhaustive(match_.match_keyword, variants, &matched, context);
+
+    BoundNode::match_(location, value, arms, type_.unwrap_or(TypeId::VOID))
+}
+
+fn bind_match_statement(
+    match_statement: parser::MatchStatement,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let (value, variants) = bind_match_value(*match_statement.value, binder, context);
+
+    let mut arms = Vec::with_capacity(match_statement.arms.len());
+    let mut matched = Vec::with_capacity(match_statement.arms.len());
+    for arm in match_statement.arms {
+        let SyntaxNodeKind::MatchStatementArm(arm) = arm.kind else {
+            bind_node(arm, binder, context);
+            continue;
+        };
+        let variant = bind_match_arm_variant(arm.variant, &value, &variants, &matched, context);
+        matched.push(variant.clone());
+        binder.create_scope();
+        let body = arm
+            .body
+            .into_iter()
+            .map(|statement| bind_node(statement, binder, context))
+            .collect();
+        binder.drop_scope();
+        arms.push(MatchStatementArm { variant, body });
+    }
+    check_match_is_exhaustive(match_statement.match_keyword, variants, &matched, context);
+
+    BoundNode::match_statement(location, value, arms)
+}
+
+/// Binds the value of a `match` and returns the variants of its enum type,
+/// if they are known.
+fn bind_match_value(
+    value: SyntaxNode,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> (BoundNode, Option<Vec<String>>) {
+    binder.push_expected_type(TypeId::ERROR);
+    let value = bind_node(value, binder, context);
+    binder.drop_expected_type();
+    let variants = match context.type_interner.resolve(value.type_) {
+        Type::Error => None,
+        // Fixed background sizes are no variant of BackgroundSize.
+        enum_type @ (Type::Enum(_)
+        | Type::ObjectFit
+        | Type::HAlign
+        | Type::VAlign
+        | Type::TextAlign
+        | Type::FontStyle
+        | Type::TextDecoration
+        | Type::TextTransform
+        | Type::WhiteSpace
+        | Type::FlexDirection) => context
+            .type_interner
+            .enum_variants(enum_type)
+            .map(|v| v.to_vec()),
+        other => {
+            context.diagnostics.report_cannot_match_on_type(
+                &context.type_interner,
+                &context.string_interner,
+                other,
+                value.location,
+            );
+            None
+        }
+    };
+    (value, variants)
+}
+
+/// Checks the variant of a match arm against the variants of the matched
+/// value and the arms before it. Returns `None` for the wildcard `_`.
+fn bind_match_arm_variant(
+    variant: Token,
+    value: &BoundNode,
+    variants: &Option<Vec<String>>,
+    matched: &[Option<String>],
+    context: &mut Context,
+) -> Option<String> {
+    let location = variant.location;
+    let variant = variant.text(&context.loaded_files);
+    if matched.contains(&None) || matched.iter().any(|v| v.as_deref() == Some(variant)) {
+        context
+            .diagnostics
+            .report_unreachable_match_arm(location, variant);
+    }
+    if variant == "_" {
+        return None;
+    }
+    if let Some(variants) = variants
+        && !variants.iter().any(|v| v == variant)
+    {
+        context.diagnostics.report_unknown_enum_variant(
+            location,
+            &context.type_interner,
+            &context.string_interner,
+            context.type_interner.resolve(value.type_),
+            variant,
+        );
+    }
+    Some(variant.to_owned())
+}
+
+fn check_match_is_exhaustive(
+    match_keyword: Token,
+    variants: Option<Vec<String>>,
+    matched: &[Option<String>],
+    context: &mut Context,
+) {
+    if let Some(variants) = variants
+        && !matched.contains(&None)
+    {
+        let missing: Vec<_> = variants
+            .into_iter()
+            .filter(|v| !matched.iter().any(|m| m.as_ref() == Some(v)))
+            .collect();
+        if !missing.is_empty() {
+            context
+                .diagnostics
+                .report_non_exhaustive_match(match_keyword.location, &missing);
+        }
+    }
+}
+
+fn bind_if(
+    if_: parser::If,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    binder.push_expected_type(TypeId::ERROR);
+    let condition = bind_node(*if_.condition, binder, context);
+    binder.drop_expected_type();
+
+    // Variables which are known to not be none inside the body or the else
+    // branch.
+    let mut body_narrowing = None;
+    let mut else_narrowing = None;
+    let (condition, optional_binding) = match if_.optional_let_binding {
+        Some((_, name, _)) => {
+            let type_ = match context.type_interner.resolve(condition.type_) {
+                Type::Optional(inner) => *inner,
+                Type::Error => TypeId::ERROR,
+                other => {
+                    context.diagnostics.report_expected_optional(
+                        &context.type_interner,
+                        &context.string_interner,
+                        other,
+                        condition.location,
+                    );
+                    TypeId::ERROR
+                }
+            };
+            let id = context
+                .string_interner
+                .create_or_get_variable(name.text(&context.loaded_files));
+            body_narrowing = Some((id, type_, name.location));
+            (condition, Some(id))
+        }
+        None => {
+            if let BoundNodeKind::Binary(binary) = &condition.kind
+                && let Some(narrowing) = narrowed_variable(binary, context)
+            {
+                match binary.operator {
+                    BoundBinaryOperator::NotEquals => body_narrowing = Some(narrowing),
+                    _ => else_narrowing = Some(narrowing),
+                }
+            }
+            let condition = bind_conversion(
+                condition,
+                TypeId::BOOL,
+                ConversionKind::Implicit,
+                binder,
+                context,
+            );
+            (condition, None)
+        }
+    };
+
+    let expected_type = binder.currently_expected_type().unwrap_or(TypeId::ERROR);
+    let expected_type = match (
+        context.type_interner.resolve(expected_type),
+        &if_.optional_else,
+    ) {
+        (Type::Optional(inner), None) => *inner,
+        _ => expected_type,
+    };
+    let body = bind_if_branch(*if_.body, body_narrowing, expected_type, binder, context);
+    let optional_else = if_.optional_else.map(|(_, _, else_body)| {
+        let else_body = bind_if_branch(*else_body, else_narrowing, body.type_, binder, context);
+        bind_conversion(
+            else_body,
+            body.type_,
+            ConversionKind::Implicit,
+            binder,
+            context,
+        )
+    });
+    let type_ = match context.type_interner.resolve(body.type_) {
+        _ if optional_else.is_some() => body.type_,
+        Type::Error | Type::Void | Type::None | Type::Optional(_) => body.type_,
+        _ => context
+            .type_interner
+            .get_or_intern(Type::Optional(body.type_)),
+    };
+    BoundNode::if_(
+        location,
+        optional_binding,
+        condition,
+        body,
+        optional_else,
+        type_,
+    )
+}
+
+fn bind_if_branch(
+    branch: SyntaxNode,
+    narrowing: Option<(VariableId, TypeId, Location)>,
+    expected_type: TypeId,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    binder.create_scope();
+    if let Some((variable, type_, location)) = narrowing {
+        binder.expect_register_variable_id(variable, type_, location, context);
+    }
+    binder.push_expected_type(expected_type);
+    let branch = bind_node(branch, binder, context);
+    binder.drop_expected_type();
+    binder.drop_scope();
+    branch
+}
+
+/// Finds the optional variable in comparisons like `x != none` or `x == none`.
+fn narrowed_variable(binary: &Binary, context: &Context) -> Option<(VariableId, TypeId, Location)> {
+    if !matches!(
+        binary.operator,
+        BoundBinaryOperator::Equals | BoundBinaryOperator::NotEquals
+    ) {
+        return None;
+    }
+    let variable = match (&binary.lhs.kind, &binary.rhs.kind) {
+        (BoundNodeKind::VariableReference(variable), _) if binary.rhs.type_ == TypeId::NONE => {
+            variable
+        }
+        (_, BoundNodeKind::VariableReference(variable)) if binary.lhs.type_ == TypeId::NONE => {
+            variable
+        }
+        _ => return None,
+    };
+    let inner = context
+        .type_interner
+        .resolve(variable.type_)
+        .try_as_optional_ref()?;
+    Some((variable.id, *inner, variable.definition))
+}
+
 fn bind_element_statement(
     element_statement: parser::ElementStatement,
     location: Location,
@@ -1470,14 +2537,32 @@ fn bind_element_statement(
             .try_as_parameter_block()
             .expect("Parameters should be parameters"),
         element_statement.parameters.location,
+        None,
         binder,
         context,
     );
     let mut body = Vec::with_capacity(element_statement.body.len());
     let mut members = HashMap::new();
+    let mut properties = Vec::new();
 
     for statement in element_statement.body {
-        let statement = bind_node(statement, binder, context);
+        let statement = match statement.kind {
+            SyntaxNodeKind::VariableDeclaration(variable_declaration)
+                if variable_declaration.let_keyword.kind == TokenKind::PropKeyword =>
+            {
+                let statement = BoundNode::property_declaration(bind_variable_declaration(
+                    variable_declaration,
+                    statement.location,
+                    binder,
+                    context,
+                ));
+                if let BoundNodeKind::PropertyDeclaration(property) = &statement.kind {
+                    properties.push((property.variable, property.value.type_));
+                }
+                statement
+            }
+            _ => bind_node(statement, binder, context),
+        };
         // if let BoundNodeKind::AssignmentStatement(a) = &statement.kind {
         //     if let BoundNodeKind::VariableReference(members_var) = &a.lhs.kind {
         //         if members_var.id == members_id {
@@ -1528,6 +2613,9 @@ fn bind_element_statement(
     binder
         .register_type_by_name(element_type, type_name_symbol)
         .expect("Check this!");
+    binder
+        .element_properties
+        .insert(element_type, properties.clone());
 
     let function_type = Type::Function(FunctionType {
         min_argument_count: parameters.iter().filter(|p| p.value.is_none()).count(),
@@ -1543,10 +2631,14 @@ fn bind_element_statement(
     ) else {
         return BoundNode::error(element_statement.name.location);
     };
+    binder.register_function_parameters(name, parameters.clone());
+    let children_parameter = find_children_parameter(&parameters, context);
     BoundNode::element_statement(
         location,
         element_type,
         parameters,
+        children_parameter,
+        properties.into_iter().map(|(id, _)| id).collect(),
         function_type,
         name,
         body,
@@ -1559,14 +2651,21 @@ fn bind_template_statement(
     binder: &mut Binder,
     context: &mut Context,
 ) -> BoundNode {
+    let is_layout = template_statement.template_keyword.kind == TokenKind::LayoutKeyword;
     let _scope = binder.create_scope();
-    let id = context
-        .string_interner
-        .create_or_get_variable("slide_index");
-    let type_ = context.type_interner.get_or_intern(Type::Integer);
-    binder
-        .expect_register_variable_id(id, type_, location, context)
-        .expect("is free");
+    let optional_integer = context
+        .type_interner
+        .get_or_intern(Type::Optional(TypeId::INTEGER));
+    for (name, type_) in [
+        ("slide_index", TypeId::INTEGER),
+        ("section_number", optional_integer),
+        ("page_number", optional_integer),
+    ] {
+        let id = context.string_interner.create_or_get_variable(name);
+        binder
+            .expect_register_variable_id(id, type_, location, context)
+            .expect("is free");
+    }
     let parameters = bind_parameter_block(
         template_statement
             .parameters
@@ -1574,6 +2673,7 @@ fn bind_template_statement(
             .try_as_parameter_block()
             .expect("Parameters should be parameters"),
         template_statement.parameters.location,
+        is_layout.then_some(TypeId::ELEMENT),
         binder,
         context,
     );
@@ -1607,12 +2707,39 @@ fn bind_template_statement(
     ) else {
         return BoundNode::error(template_statement.name.location);
     };
-    BoundNode::template_statement(location, parameters, function_type, name, body)
+    binder.register_function_parameters(name, parameters.clone());
+    if is_layout {
+        binder.layouts.insert(name);
+    }
+    let children_parameter = find_children_parameter(&parameters, context);
+    BoundNode::template_statement(
+        location,
+        parameters,
+        children_parameter,
+        function_type,
+        name,
+        body,
+    )
+}
+
+/// Finds the parameter, which receives the elements of a trailing body. This
+/// is the last parameter, which accepts elements.
+fn find_children_parameter(parameters: &[Parameter], context: &mut Context) -> Option<VariableId> {
+    let argument_types: Vec<TypeId> = parameters.iter().map(|p| p.type_).collect();
+    find_children_slot(&argument_types, context).map(|index| parameters[index].id)
+}
+
+fn find_children_slot(argument_types: &[TypeId], context: &mut Context) -> Option<usize> {
+    let element_array = context
+        .type_interner
+        .get_or_intern(Type::Array(TypeId::ELEMENT));
+    argument_types.iter().rposition(|&t| t == element_array)
 }
 
 fn bind_parameter_block(
     parameter_block: parser::ParameterBlock,
     _location: Location,
+    default_type: Option<TypeId>,
     binder: &mut Binder,
     context: &mut Context,
 ) -> Vec<Parameter> {
@@ -1622,34 +2749,58 @@ fn bind_parameter_block(
         let Some(parameter) = parameter.kind.try_as_parameter() else {
             continue;
         };
-        let type_ = bind_type_node(parameter.type_, binder, context);
-        let variable = context
-            .string_interner
-            .create_or_get_variable(parameter.identifier.text(&context.loaded_files));
-        let variable = match binder.expect_register_variable_id(variable, type_, location, context)
-        {
-            Some(it) => it,
-            None => variable,
-        };
-        let value = match parameter.optional_initializer {
-            Some(it) => bind_node(*it, binder, context).constant_value,
-            None => None,
-        };
-        result.push(Parameter {
-            id: variable,
-            value,
-        });
+        result.push(bind_parameter(
+            parameter,
+            location,
+            default_type,
+            binder,
+            context,
+        ));
     }
     result
 }
 
-fn bind_post_initialization(
-    post_initialization: parser::PostInitialization,
+fn bind_parameter(
+    parameter: parser::Parameter,
     location: Location,
+    inferred_type: Option<TypeId>,
     binder: &mut Binder,
     context: &mut Context,
-) -> BoundNode {
-    binder.push_expected_type(TypeId::ERROR);
+) -> Parameter {
+    let name = parameter.identifier.text(&context.loaded_files).to_owned();
+    let type_ = match (parameter.optional_type, inferred_type) {
+        (Some(type_), _) => bind_type_node(type_, binder, context),
+        (None, Some(type_)) if type_ != TypeId::ERROR => type_,
+        (None, _) => {
+            context
+                .diagnostics
+                .report_cannot_infer_parameter_type(location, &name);
+            TypeId::ERROR
+        }
+    };
+    let variable = context.string_interner.create_or_get_variable(&name);
+    let variable = match binder.expect_register_variable_id(variable, type_, location, context) {
+        Some(it) => it,
+        None => variable,
+    };
+    let value = match parameter.optional_initializer {
+        Some(it) => bind_node(*it, binder, context).constant_value,
+        None => None,
+    };
+    Parameter {
+        id: variable,
+        type_,
+        value,
+    }
+}
+
+fn bind_post_initialization(
+    post_initialization: parser::PostInitialization,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    binder.push_expected_type(TypeId::ERROR);
     let base = bind_node(*post_initialization.expression, binder, context);
     binder.drop_expected_type();
     let dict_location = post_initialization.dict.location;
@@ -1732,6 +2883,13 @@ fn access_member(
     member: SymbolUsize,
     base_type: Type,
 ) -> Option<TypeId> {
+    if let Type::TypedDict(fields) = &base_type {
+        let name = context.string_interner.resolve(member).to_owned();
+        let id = context.string_interner.create_or_get_variable(&name);
+        if let Some((_, type_)) = fields.iter().find(|(field, _)| *field == id) {
+            return Some(*type_);
+        }
+    }
     let mut types_to_check = vec![base_type];
     let mut visited = Vec::new();
     while let Some(base_type) = types_to_check.pop() {
@@ -1879,6 +3037,9 @@ fn bind_type_node(type_: parser::TypeNode, binder: &mut Binder, context: &mut Co
         }
     }
     let mut base = base.expect("This should be set here");
+    if type_.optional_array.is_some() {
+        base = context.type_interner.get_or_intern(Type::Array(base))
+    }
     if type_.question_mark.is_some() {
         base = context.type_interner.get_or_intern(Type::Optional(base))
     }
@@ -1891,19 +3052,100 @@ fn bind_slide_statement(
     binder: &mut Binder,
     context: &mut Context,
 ) -> BoundNode {
-    let scope = binder.create_scope();
-    for (name, type_) in globals::find_members_by_name("Slide") {
-        let id = context.string_interner.create_or_get_variable(name);
-        let type_ = context.type_interner.get_or_intern(type_);
-        scope
-            .try_register_variable(id, type_, slide_statement.name.location)
-            .expect("infallible");
-    }
-    let mut statements = Vec::with_capacity(slide_statement.body.len());
+    let base = slide_statement.optional_extends.and_then(|(_, base)| {
+        let text = base.text(&context.loaded_files);
+        let id = context.string_interner.create_or_get_variable(text);
+        let slide = binder.slides.get(&id).cloned();
+        if slide.is_none() {
+            context
+                .diagnostics
+                .report_unknown_slide(base.location, text);
+        }
+        slide
+    });
+    // The body of the base slide is evaluated first, so the statements of
+    // this slide can override, restyle or remove its elements.
+    let SlideBase {
+        scope,
+        body: mut statements,
+        layout,
+    } = base.unwrap_or_else(|| {
+        let mut scope = Scope::new();
+        for (name, type_) in globals::find_members_by_name("Slide") {
+            let id = context.string_interner.create_or_get_variable(name);
+            let type_ = context.type_interner.get_or_intern(type_);
+            scope
+                .try_register_variable(id, type_, slide_statement.name.location)
+                .expect("infallible");
+        }
+        SlideBase {
+            scope,
+            body: Vec::new(),
+            layout: None,
+        }
+    });
+    binder.slide_scope = Some(binder.scopes.len());
+    binder.scopes.push(scope);
+    binder.create_scope();
+    let inherited_statements = statements.len();
     for statement in slide_statement.body {
-        statements.push(bind_node(statement, binder, context));
+        let overridden =
+            statement
+                .kind
+                .try_as_variable_declaration_ref()
+                .and_then(|variable_declaration| {
+                    let name = variable_declaration.name.text(&context.loaded_files);
+                    let id = context.string_interner.create_or_get_variable(name);
+                    statements[..inherited_statements].iter().position(|s| {
+                        s.kind
+                            .try_as_variable_declaration_ref()
+                            .is_some_and(|d| d.variable == id)
+                    })
+                });
+        match overridden {
+            Some(index) => {
+                let later_declarations: Vec<VariableId> = statements[index..inherited_statements]
+                    .iter()
+                    .filter_map(|s| s.kind.try_as_variable_declaration_ref())
+                    .map(|d| d.variable)
+                    .collect();
+                let expected_type = statements[index].kind.as_variable_declaration().value.type_;
+                statements[index] = bind_overriding_declaration(
+                    statement,
+                    expected_type,
+                    &later_declarations,
+                    binder,
+                    context,
+                );
+            }
+            None => statements.push(bind_node(statement, binder, context)),
+        }
     }
-    let scope = binder.drop_scope();
+    let numbered = context.string_interner.create_or_get_variable("numbered");
+    for statement in &statements[inherited_statements..] {
+        if let BoundNodeKind::AssignmentStatement(assignment) = &statement.kind
+            && let BoundNodeKind::VariableReference(variable) = &assignment.lhs.kind
+            && variable.id == numbered
+            && assignment.value.constant_value.is_none()
+            && assignment.value.type_ != TypeId::ERROR
+        {
+            context
+                .diagnostics
+                .report_expected_constant("numbered", assignment.value.location);
+        }
+    }
+    let layout = slide_statement
+        .optional_uses
+        .map(|(_, layout)| layout)
+        .or(layout);
+    let layout_statements = match layout {
+        Some(layout) => bind_layout_call(layout, location, binder, context),
+        None => Vec::new(),
+    };
+    let own_scope = binder.drop_scope();
+    let mut scope = binder.drop_scope();
+    scope.variables.extend(own_scope.variables);
+    binder.slide_scope = None;
     debug_scope(
         &format!("slide {}", slide_statement.name.text(&context.loaded_files)),
         &scope,
@@ -1919,9 +3161,98 @@ fn bind_slide_statement(
     ) else {
         return BoundNode::error(slide_statement.name.location);
     };
+    binder.slides.insert(
+        name,
+        SlideBase {
+            scope,
+            body: statements.clone(),
+            layout,
+        },
+    );
+    statements.extend(layout_statements);
     BoundNode::slide_statement(slide_statement, location, name, statements)
 }
 
+/// Calls the layout of a slide with the elements of the slide, which are
+/// named like its placeholders. These elements are then only added to the
+/// slide by the layout.
+fn bind_layout_call(
+    layout: Token,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> Vec<BoundNode> {
+    let name = layout.text(&context.loaded_files);
+    let id = context.string_interner.create_or_get_variable(name);
+    let Some(variable) = binder
+        .layouts
+        .contains(&id)
+        .then(|| binder.look_up_variable(id))
+        .flatten()
+        .cloned()
+    else {
+        context
+            .diagnostics
+            .report_unknown_layout(layout.location, name);
+        return Vec::new();
+    };
+    let function_type = context
+        .type_interner
+        .resolve(variable.type_)
+        .try_as_function_ref()
+        .expect("layouts are functions")
+        .clone();
+    let parameters = binder
+        .look_up_function_parameters(id, context)
+        .expect("layouts have parameters")
+        .clone();
+    let slide_scope = binder.slide_scope.expect("only used in slides");
+    let mut arguments = Vec::with_capacity(parameters.len());
+    let mut placeholders = Vec::new();
+    for (parameter, &type_) in parameters.iter().zip(&function_type.argument_types) {
+        let element = binder.scopes[slide_scope..]
+            .iter()
+            .rev()
+            .find_map(|scope| scope.look_up(parameter.id))
+            .cloned();
+        match (element, &parameter.value) {
+            (Some(element), _) => {
+                let argument = BoundNode::fake_variable_reference(&element, location);
+                arguments.push(bind_conversion(
+                    argument,
+                    type_,
+                    ConversionKind::Implicit,
+                    binder,
+                    context,
+                ));
+                placeholders.push(parameter.id);
+            }
+            (None, Some(value)) => arguments.push(BoundNode::fake_literal(value.clone())),
+            (None, None) => {
+                context.diagnostics.report_unfilled_placeholder(
+                    location,
+                    name,
+                    context.string_interner.resolve_variable(parameter.id),
+                );
+                return Vec::new();
+            }
+        }
+    }
+    let base = BoundNode::variable_reference(layout, &variable);
+    let mut statements = vec![BoundNode::function_call(
+        layout.location,
+        base,
+        arguments,
+        function_type,
+    )];
+    statements.extend(
+        placeholders
+            .into_iter()
+            .map(|placeholder| BoundNode::remove_statement(placeholder, location)),
+    );
+    statements
+}
+
 fn bind_global_statement(
     mut global_statement: parser::GlobalStatement,
     location: Location,
@@ -1939,14 +3270,76 @@ fn bind_global_statement(
     BoundNode::global_statement(global_statement, location, statements)
 }
 
+/// Binds the declarations of a `tokens:` block. A token which was already
+/// declared, e.g. by the theme, is redeclared with the same type.
+fn bind_tokens_statement(
+    global_statement: parser::GlobalStatement,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let mut tokens = Vec::with_capacity(global_statement.body.len());
+    for statement in global_statement.body {
+        let SyntaxNodeKind::VariableDeclaration(variable_declaration) = statement.kind else {
+            context
+                .diagnostics
+                .report_invalid_token_statement(statement.location);
+            continue;
+        };
+        if variable_declaration.let_keyword.kind != TokenKind::LetKeyword {
+            context
+                .diagnostics
+                .report_invalid_token_statement(statement.location);
+            continue;
+        }
+        let name = variable_declaration.name.text(&context.loaded_files);
+        let variable = context.string_interner.create_or_get_variable(name);
+        let declaration = match binder.tokens.get(&variable).copied() {
+            Some(type_) => {
+                binder.push_expected_type(type_);
+                let value = bind_node(*variable_declaration.expression, binder, context);
+                binder.drop_expected_type();
+                BoundNode::variable_declaration(statement.location, variable, value)
+            }
+            None => {
+                bind_variable_declaration(variable_declaration, statement.location, binder, context)
+            }
+        };
+        let BoundNodeKind::VariableDeclaration(declaration) = declaration.kind else {
+            continue;
+        };
+        let type_ = declaration.value.type_;
+        match context.type_interner.resolve(type_) {
+            Type::Color | Type::StyleUnit | Type::Font => {
+                binder.tokens.insert(variable, type_);
+                tokens.push(declaration);
+            }
+            Type::Error => {}
+            _ => {
+                let type_ = context
+                    .type_interner
+                    .id_to_simple_string(type_, &context.string_interner);
+                context
+                    .diagnostics
+                    .report_invalid_token_type(declaration.value.location, &type_);
+            }
+        }
+    }
+    BoundNode::tokens_statement(tokens, location)
+}
+
 fn bind_typed_string(
     typed_string: parser::TypedString,
     location: Location,
     binder: &mut Binder,
     context: &mut Context,
 ) -> BoundNode {
+    if typed_string.type_.text(&context.loaded_files) == "r" {
+        // Raw strings keep their escapes as written.
+        return bind_string(typed_string.string, false, binder, context);
+    }
     binder.push_expected_type(TypeId::STRING);
-    let literal = bind_string(typed_string.string, binder, context);
+    let literal = bind_string(typed_string.string, true, binder, context);
     binder.drop_expected_type();
     let type_ = typed_string.type_.text(&context.loaded_files);
     let type_ = match type_ {
@@ -1965,80 +3358,324 @@ fn bind_typed_string(
     bind_conversion(literal, type_, ConversionKind::TypedString, binder, context)
 }
 
-fn bind_string(string: Token, binder: &mut Binder, context: &mut Context) -> BoundNode {
+fn bind_string(
+    string: Token,
+    replace_escapisms: bool,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
     let text = string.text(&context.loaded_files);
     if string.kind == TokenKind::String {
-        let value = Value::parse_string_literal(text, true, true);
+        let value = Value::parse_string_literal(text, replace_escapisms, true);
         let type_ = context.type_interner.get_or_intern(value.infer_type());
-        BoundNode::literal(string, value, type_)
-    } else {
-        let text = text.strip_prefix('\'').unwrap().strip_suffix('\'').unwrap();
-        let text = text.to_owned();
-        let parts = text.split('{');
-        let mut values = Vec::new();
-        let mut offset = 0;
-        for part in parts {
-            offset += 1;
-            let (expression, literal) = if part.contains('}') {
-                let (expression, literal) = part.split_once('}').unwrap();
-                (Some(expression), literal)
-            } else {
-                (None, part)
-            };
-            if let Some(expression) = expression {
-                let location = Location {
-                    file: string.location.file,
-                    start: string.location.start + offset,
-                    length: expression.len(),
+        return BoundNode::literal(string, value, type_);
+    }
+    // Everything between the quotes of the format string.
+    let text = text.to_owned();
+    let content = 1..text.len() - 1;
+    let mut values = Vec::new();
+    let mut literal_start = content.start;
+    let mut chars = text[..content.end].char_indices().skip(1);
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => {
+                chars.next();
+            }
+            '{' => {
+                values.push(bind_format_literal(
+                    &string,
+                    &text[literal_start..index],
+                    literal_start,
+                    replace_escapisms,
+                ));
+                let Some(placeholder) = scan_format_placeholder(&text[..content.end], index) else {
+                    context
+                        .diagnostics
+                        .report_unclosed_format_placeholder(string.location.slice(index, 1));
+                    literal_start = content.end;
+                    break;
                 };
-                offset += expression.len() + 1;
-                binder.push_expected_type(TypeId::ERROR);
-                let base = bind_node_from_source(location, binder, context);
-                binder.drop_expected_type();
-                values.push(bind_conversion(
-                    base,
-                    TypeId::STRING,
-                    ConversionKind::ToString,
+                let end = placeholder.end;
+                values.push(bind_format_placeholder(
+                    &string,
+                    &text,
+                    placeholder,
                     binder,
                     context,
                 ));
+                chars.find(|&(i, _)| i == end);
+                literal_start = end + 1;
             }
-            values.push(BoundNode::literal(
-                Token {
-                    location: Location {
-                        file: string.location.file,
-                        start: string.location.start + offset,
-                        length: literal.len(),
-                    },
-                    kind: TokenKind::String,
-                    trivia: Trivia::default(),
-                },
-                Value::parse_string_literal(literal, true, false),
-                TypeId::STRING,
-            ));
-            offset += part.len();
+            '}' => {
+                context
+                    .diagnostics
+                    .report_unmatched_closing_brace(string.location.slice(index, 1));
+            }
+            _ => {}
+        }
+    }
+    values.push(bind_format_literal(
+        &string,
+        &text[literal_start..content.end],
+        literal_start,
+        replace_escapisms,
+    ));
+    let string_array = context
+        .type_interner
+        .get_or_intern(Type::Array(TypeId::STRING));
+    bind_builtin_call(
+        "concat",
+        vec![BoundNode::array(values, string.location, string_array)],
+        string.location,
+        binder,
+        context,
+    )
+}
+
+fn bind_format_literal(
+    string: &Token,
+    literal: &str,
+    offset: usize,
+    replace_escapisms: bool,
+) -> BoundNode {
+    BoundNode::literal(
+        Token {
+            location: string.location.slice(offset, literal.len()),
+            kind: TokenKind::String,
+            trivia: Trivia::default(),
+        },
+        Value::parse_string_literal(literal, replace_escapisms, false),
+        TypeId::STRING,
+    )
+}
+
+/// The byte ranges of a `{expression:spec}` placeholder inside of a format
+/// string, relative to the start of the string token.
+struct FormatPlaceholder {
+    expression: std::ops::Range<usize>,
+    spec: Option<std::ops::Range<usize>>,
+    /// The index of the closing brace.
+    end: usize,
+}
+
+/// Finds the closing brace of the placeholder opened at `start`. Braces,
+/// brackets and parentheses inside of the expression are balanced and strings
+/// are skipped, so `{join(["a", "}"], ", ")}` is a single placeholder. The
+/// spec follows the last top level `:`, which does not belong to an `if` or
+/// `match`, and only if the text after it parses as a spec. This keeps
+/// `{if a: b}` an expression.
+fn scan_format_placeholder(text: &str, start: usize) -> Option<FormatPlaceholder> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut colon = None;
+    // Colons still expected by top level `if` and `match` keywords.
+    let mut keyword_colons = 0;
+    let mut word_start = None;
+    let mut chars = text[start + 1..]
+        .char_indices()
+        .map(|(i, c)| (i + start + 1, c));
+    while let Some((index, char)) = chars.next() {
+        if char.is_alphanumeric() || char == '_' {
+            word_start.get_or_insert(index);
+            continue;
+        }
+        if let Some(word_start) = word_start.take()
+            && !in_string
+            && depth == 0
+            && matches!(&text[word_start..index], "if" | "match")
+        {
+            keyword_colons += 1;
+        }
+        match char {
+            '\\' => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' if depth > 0 => depth -= 1,
+            '}' if depth > 0 => depth -= 1,
+            ':' if depth == 0 && keyword_colons > 0 => keyword_colons -= 1,
+            ':' if depth == 0 => colon = Some(index),
+            '}' => {
+                let spec = colon
+                    .map(|colon| colon + 1..index)
+                    .filter(|spec| FormatSpec::parse(&text[spec.clone()]).is_some());
+                let expression_end = spec.as_ref().map_or(index, |spec| spec.start - 1);
+                return Some(FormatPlaceholder {
+                    expression: start + 1..expression_end,
+                    spec,
+                    end: index,
+                });
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// A format spec like `02`, `8` or `.2`: zero padding, minimum width and
+/// number of decimal places.
+#[derive(Debug)]
+struct FormatSpec {
+    zero_padded: bool,
+    width: Option<i64>,
+    precision: Option<i64>,
+}
+
+impl FormatSpec {
+    fn parse(spec: &str) -> Option<Self> {
+        if !spec.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return None;
+        }
+        let (width, precision) = match spec.split_once('.') {
+            Some((width, precision)) => (width, Some(precision.parse().ok()?)),
+            None => (spec, None),
+        };
+        let zero_padded = width.starts_with('0');
+        let width = if width.is_empty() {
+            None
+        } else {
+            Some(width.parse().ok()?)
+        };
+        Some(Self {
+            zero_padded,
+            width,
+            precision,
+        })
+    }
+}
+
+fn bind_format_placeholder(
+    string: &Token,
+    text: &str,
+    placeholder: FormatPlaceholder,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let location = string
+        .location
+        .slice(placeholder.expression.start, placeholder.expression.len());
+    binder.push_expected_type(TypeId::ERROR);
+    let value = bind_node_from_source(location, binder, context);
+    binder.drop_expected_type();
+    let Some(spec) = placeholder.spec else {
+        return bind_conversion(
+            value,
+            TypeId::STRING,
+            ConversionKind::ToString,
+            binder,
+            context,
+        );
+    };
+    let spec_location = string.location.slice(spec.start, spec.len());
+    let spec = &text[spec];
+    let is_number = matches!(
+        context.type_interner.resolve(value.type_),
+        Type::Integer | Type::Float
+    );
+    let format_spec = FormatSpec::parse(spec)
+        .filter(|spec| is_number || !spec.zero_padded && spec.precision.is_none());
+    let Some(format_spec) = format_spec else {
+        if value.type_ != TypeId::ERROR {
+            context.diagnostics.report_invalid_format_spec(
+                &context.type_interner,
+                &context.string_interner,
+                context.type_interner.resolve(value.type_),
+                spec,
+                spec_location,
+            );
+        }
+        return BoundNode::error(spec_location);
+    };
+    let mut result = match format_spec.precision {
+        Some(precision) => {
+            let value = bind_conversion(
+                value,
+                TypeId::FLOAT,
+                ConversionKind::Implicit,
+                binder,
+                context,
+            );
+            let precision = bind_format_literal_value(
+                spec_location,
+                Value::Integer(precision),
+                TypeId::INTEGER,
+            );
+            bind_builtin_call("fixed", vec![value, precision], location, binder, context)
         }
-        let concat_id = context.string_interner.create_or_get_variable("concat");
-        let var = binder.look_up_variable(concat_id).unwrap();
+        None => bind_conversion(
+            value,
+            TypeId::STRING,
+            ConversionKind::ToString,
+            binder,
+            context,
+        ),
+    };
+    if let Some(width) = format_spec.width {
+        let width =
+            bind_format_literal_value(spec_location, Value::Integer(width), TypeId::INTEGER);
+        result = if format_spec.zero_padded {
+            // Zeros go between the sign and the digits.
+            bind_builtin_call("pad_zeros", vec![result, width], location, binder, context)
+        } else {
+            let fill =
+                bind_format_literal_value(spec_location, Value::String(" ".into()), TypeId::STRING);
+            bind_builtin_call(
+                "pad_left",
+                vec![result, width, fill],
+                location,
+                binder,
+                context,
+            )
+        };
+    }
+    result
+}
+
+fn bind_format_literal_value(location: Location, value: Value, type_: TypeId) -> BoundNode {
+    BoundNode::literal(
+        Token {
+            location,
+            kind: TokenKind::Number,
+            trivia: Trivia::default(),
+        },
+        value,
+        type_,
+    )
+}
+
+/// Calls one of the functions from [`globals::FUNCTIONS`], for syntax that is
+/// lowered to a builtin, like format strings. The arguments have to match the
+/// parameter types already.
+fn bind_builtin_call(
+    name: &str,
+    arguments: Vec<BoundNode>,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let id = context.string_interner.create_or_get_variable(name);
+    let Some((var, function_type)) = binder.look_up_global(id).and_then(|var| {
         let function_type = context
             .type_interner
             .resolve(var.type_)
             .clone()
-            .try_as_function()
-            .unwrap();
-        let string_array = context
-            .type_interner
-            .get_or_intern(Type::Array(TypeId::STRING));
-        BoundNode::function_call(
-            string.location,
-            BoundNode::variable_reference(
-                Token::fabricate(TokenKind::Identifier, Location::zero()),
-                var,
-            ),
-            vec![BoundNode::array(values, string.location, string_array)],
-            function_type,
-        )
-    }
+            .try_as_function()?;
+        Some((var, function_type))
+    }) else {
+        context.diagnostics.report_unknown_variable(location, name);
+        return BoundNode::error(location);
+    };
+    BoundNode::function_call(
+        location,
+        BoundNode::variable_reference(
+            Token::fabricate(TokenKind::Identifier, Location::zero()),
+            var,
+        ),
+        arguments,
+        function_type,
+    )
 }
 
 fn bind_conversion(
@@ -2069,6 +3706,13 @@ fn bind_conversion(
         ConversionKind::Implicit => match context.type_interner.resolve_types([base.type_, target])
         {
             [Type::Integer, Type::Float] => {}
+            [Type::Integer | Type::Float, Type::Angle] => {}
+            // Plain numbers are multiples of the default font size.
+            [
+                Type::Integer | Type::Float | Type::StyleUnit,
+                Type::FontSize,
+            ] => {}
+            [Type::FontSize, Type::StyleUnit] => {}
             [_, Type::Optional(to)] if base.type_ == *to => {}
             [Type::TypedDict(fields), Type::Struct(struct_data)] => {
                 let mut all_fields_assigned: HashMap<_, _> = struct_data
@@ -2114,14 +3758,23 @@ fn bind_conversion(
                     return BoundNode::error(base.location);
                 }
             }
-            [from @ Type::TypedDict(fields), Type::Thickness] => {
+            [
+                from @ Type::TypedDict(fields),
+                to @ (Type::Thickness | Type::Border),
+            ] => {
+                // A border takes each side from a border, which is given for
+                // that side.
+                let (side_type, side) = match to {
+                    Type::Thickness => (style_unit_type, &Type::StyleUnit),
+                    _ => (TypeId::BORDER, &Type::Border),
+                };
                 for (name, type_) in fields {
-                    if *type_ != style_unit_type {
+                    if *type_ != side_type {
                         context.diagnostics.report_cannot_convert(
                             &context.type_interner,
                             &context.string_interner,
                             context.type_interner.resolve(*type_),
-                            &Type::StyleUnit,
+                            side,
                             base.location,
                         );
                         return BoundNode::error(base.location);
@@ -2133,7 +3786,7 @@ fn bind_conversion(
                             &context.type_interner,
                             &context.string_interner,
                             from,
-                            &Type::StyleUnit,
+                            side,
                             base.location,
                         );
                         return BoundNode::error(base.location);
@@ -2178,7 +3831,22 @@ fn bind_conversion(
                     return BoundNode::error(base.location);
                 }
             }
-            [Type::Color, Type::Background] => {}
+            [Type::Color, Type::Background | Type::ColorStop] => {}
+            // Strings which are not known while binding are checked by the
+            // evaluator.
+            [Type::String, Type::GridCellSizes] => {
+                if let Some(Value::String(text)) = &base.constant_value
+                    && let Err(message) = evaluator::functions::parse_grid_cell_sizes(text)
+                {
+                    context
+                        .diagnostics
+                        .report_invalid_grid_cell_sizes(base.location, message);
+                    return BoundNode::error(base.location);
+                }
+            }
+            // An array of filters or transforms is applied as a chain.
+            [Type::Array(inner), to @ (Type::Filter | Type::Transform)]
+                if context.type_interner.resolve(*inner) == to => {}
             [
                 Type::Label | Type::Image | Type::CustomElement(_, _) | Type::Grid | Type::Flex,
                 Type::Element,
@@ -2209,11 +3877,13 @@ fn bind_conversion(
         },
         ConversionKind::ToString => match context.type_interner.resolve(base.type_) {
             Type::Error => return base,
-            Type::Float | Type::Integer | Type::Path | Type::Optional(TypeId::STRING) => {}
-            Type::Optional(inner) => {
-                let inner = bind_conversion(base, *inner, conversion_kind, binder, context);
-                return BoundNode::conversion(inner, target, conversion_kind);
-            }
+            Type::Float | Type::Integer | Type::Bool | Type::Path => {}
+            // none is converted to an empty string.
+            Type::Optional(inner)
+                if matches!(
+                    context.type_interner.resolve(*inner),
+                    Type::Float | Type::Integer | Type::Bool | Type::Path | Type::String
+                ) => {}
             Type::String => return base,
             from => {
                 context.diagnostics.report_cannot_convert(
@@ -2271,16 +3941,48 @@ fn bind_literal(
     let text = token.text(&context.loaded_files);
     let value = match token.kind {
         super::lexer::TokenKind::Number => {
-            if text.contains('.') {
-                Value::Float(text.parse().expect("lexer filtered"))
+            // Underscores only separate digits.
+            let number = text.replace('_', "");
+            let value = if number.contains('.') {
+                number.parse().map(Value::Float).ok()
             } else {
-                Value::Integer(text.parse().expect("lexer filtered"))
-            }
+                number.parse().map(Value::Integer).ok()
+            };
+            let Some(value) = value else {
+                context
+                    .diagnostics
+                    .report_invalid_number(token.location, text);
+                return BoundNode::error(token.location);
+            };
+            value
         }
         super::lexer::TokenKind::String => Value::parse_string_literal(text, true, true),
         super::lexer::TokenKind::NoneKeyword => Value::none(),
+        super::lexer::TokenKind::TrueKeyword => Value::Bool(true),
+        super::lexer::TokenKind::FalseKeyword => Value::Bool(false),
         super::lexer::TokenKind::StyleUnitLiteral => {
-            Value::StyleUnit(text.parse().expect("lexer filtered"))
+            let literal = text.replace('_', "");
+            match literal.parse::<StyleUnit>() {
+                Ok(style_unit) => Value::StyleUnit(style_unit),
+                Err(StyleUnitParseError::UnknownUnits) => match literal.parse::<Angle>() {
+                    Ok(angle) => Value::Angle(angle),
+                    Err(_) => {
+                        let unit = text.trim_start_matches(|c: char| {
+                            c.is_ascii_digit() || c == '.' || c == '_'
+                        });
+                        context
+                            .diagnostics
+                            .report_unknown_unit(token.location, unit);
+                        return BoundNode::error(token.location);
+                    }
+                },
+                Err(StyleUnitParseError::ParseFloatError(_)) => {
+                    context
+                        .diagnostics
+                        .report_invalid_number(token.location, text);
+                    return BoundNode::error(token.location);
+                }
+            }
         }
         err => unreachable!("This is a unhandled literal {err:?}"),
     };
@@ -2302,6 +4004,16 @@ fn bind_variable_reference(
             .report_unknown_variable(token.location, token.text(&context.loaded_files));
         return BoundNode::error(token.location);
     };
+    // Builtins only exist in the binder, so they can be called, but not be
+    // passed around like user functions.
+    let text = token.text(&context.loaded_files);
+    if variable.definition == Location::zero() && globals::FUNCTIONS.iter().any(|f| f.name == text)
+    {
+        context
+            .diagnostics
+            .report_builtin_used_as_value(token.location, text);
+        return BoundNode::error(token.location);
+    }
     BoundNode::variable_reference(token, variable)
 }
 
@@ -2311,46 +4023,686 @@ fn bind_function_call(
     binder: &mut Binder,
     context: &mut Context,
 ) -> BoundNode {
-    binder.push_expected_type(TypeId::ERROR);
-    let base = bind_node(*function_call.base, binder, context);
-    binder.drop_expected_type();
-    let mut arguments = Vec::with_capacity(function_call.arguments.len());
-    let Some(function_type) = context
-        .type_interner
-        .resolve(base.type_)
-        .clone()
-        .try_as_function()
-    else {
-        // TODO: Report unexpected Type!
-        return BoundNode::error(base.location);
+    if let SyntaxNodeKind::VariableReference(name) = &function_call.base.kind {
+        let text = name.text(&context.loaded_files);
+        let id = context.string_interner.create_or_get_variable(text);
+        // Builtins are only shadowed by functions, not by other variables
+        // like a slide called `toc`.
+        let is_shadowed = binder.look_up_function(id, context).is_some();
+        if globals::PRESENTATION_FUNCTIONS.contains(&text) && !is_shadowed {
+            let name = *name;
+            if let Some(children) = &function_call.optional_children {
+                context
+                    .diagnostics
+                    .report_no_children_parameter(children.location);
+            }
+            return bind_presentation_function_call(name, function_call, location, binder, context);
+        }
+        if globals::ARRAY_FUNCTIONS.contains(&text) && !is_shadowed {
+            let name = *name;
+            if let Some(children) = &function_call.optional_children {
+                context
+                    .diagnostics
+                    .report_no_children_parameter(children.location);
+            }
+            return bind_array_function_call(name, function_call, location, binder, context);
+        }
+    }
+    let function = match &function_call.base.kind {
+        &SyntaxNodeKind::VariableReference(name) => {
+            let id = context
+                .string_interner
+                .create_or_get_variable(name.text(&context.loaded_files));
+            binder
+                .look_up_function(id, context)
+                .map(|variable| BoundNode::variable_reference(name, variable))
+        }
+        _ => None,
     };
-    for ((argument, _), type_) in function_call
-        .arguments
-        .into_iter()
-        .zip(&function_type.argument_types)
-    {
-        binder.push_expected_type(*type_);
-        arguments.push(bind_conversion(
-            bind_node(argument, binder, context),
-            *type_,
-            ConversionKind::Implicit,
-            binder,
-            context,
-        ));
+    let base = function.unwrap_or_else(|| {
+        binder.push_expected_type(TypeId::ERROR);
+        let base = bind_node(*function_call.base, binder, context);
         binder.drop_expected_type();
+        base
+    });
+    let Some(function_type) = context
+        .type_interner
+        .resolve(base.type_)
+        .clone()
+        .try_as_function()
+    else {
+        // TODO: Report unexpected Type!
+        return BoundNode::error(base.location);
+    };
+    let parameters = match &base.kind {
+        BoundNodeKind::VariableReference(variable) => binder
+            .look_up_function_parameters(variable.id, context)
+            .cloned(),
+        BoundNodeKind::MemberAccess(member_access) => {
+            module_function_parameters(member_access, &function_type, context)
+        }
+        _ => None,
+    };
+
+    let mut slots: Vec<Option<SyntaxNode>> = vec![None; function_type.argument_types.len()];
+    let mut positional_count = 0;
+    let mut has_named_arguments = false;
+    for (argument, _) in function_call.arguments {
+        let SyntaxNodeKind::NamedArgument(named_argument) = argument.kind else {
+            if has_named_arguments {
+                context
+                    .diagnostics
+                    .report_positional_after_named_argument(argument.location);
+            }
+            if let Some(slot) = slots.get_mut(positional_count) {
+                *slot = Some(argument);
+            }
+            positional_count += 1;
+            continue;
+        };
+        has_named_arguments = true;
+        let name = named_argument.name.text(&context.loaded_files);
+        let Some(parameters) = &parameters else {
+            if is_module_function(&base, context) {
+                context
+                    .diagnostics
+                    .report_module_named_arguments_not_supported(
+                        named_argument.name.location,
+                        name,
+                    );
+            } else {
+                context
+                    .diagnostics
+                    .report_named_arguments_not_supported(named_argument.name.location, name);
+            }
+            continue;
+        };
+        let id = context.string_interner.create_or_get_variable(name);
+        let Some(index) = parameters.iter().position(|p| p.id == id) else {
+            context.diagnostics.report_unknown_argument_name(
+                named_argument.name.location,
+                name,
+                parameters
+                    .iter()
+                    .map(|p| context.string_interner.resolve_variable(p.id))
+                    .collect(),
+            );
+            continue;
+        };
+        if slots[index].is_some() || index < positional_count {
+            context
+                .diagnostics
+                .report_duplicate_argument(named_argument.name.location, name);
+            continue;
+        }
+        slots[index] = Some(*named_argument.value);
     }
-    if !(function_type.min_argument_count..=function_type.argument_types.len())
-        .contains(&arguments.len())
-    {
+
+    if let Some(children) = function_call.optional_children {
+        match find_children_slot(&function_type.argument_types, context) {
+            Some(index) if slots[index].is_some() || index < positional_count => {
+                let name = match &parameters {
+                    Some(parameters) => context
+                        .string_interner
+                        .resolve_variable(parameters[index].id)
+                        .to_owned(),
+                    None => format!("argument {}", index + 1),
+                };
+                context
+                    .diagnostics
+                    .report_children_already_passed(children.location, &name);
+            }
+            Some(index) => slots[index] = Some(*children),
+            None => context
+                .diagnostics
+                .report_no_children_parameter(children.location),
+        }
+    }
+
+    if positional_count > function_type.argument_types.len() {
         context
             .diagnostics
-            .report_wrong_argument_count(location, function_type, arguments.len());
+            .report_wrong_argument_count(location, function_type, positional_count);
+        return BoundNode::error(location);
+    }
+
+    let mut arguments = Vec::with_capacity(slots.len());
+    let mut is_missing_arguments = false;
+    for (index, (slot, type_)) in slots
+        .into_iter()
+        .zip(function_type.argument_types.iter().copied())
+        .enumerate()
+    {
+        let default_value = parameters.as_ref().and_then(|p| p[index].value.clone());
+        let is_required = match &parameters {
+            Some(_) => default_value.is_none(),
+            None => index < function_type.min_argument_count,
+        };
+        match slot {
+            Some(argument) => {
+                binder.push_expected_type(type_);
+                arguments.push(bind_conversion(
+                    bind_node(argument, binder, context),
+                    type_,
+                    ConversionKind::Implicit,
+                    binder,
+                    context,
+                ));
+                binder.drop_expected_type();
+            }
+            None if is_required => {
+                is_missing_arguments = true;
+                if has_named_arguments && let Some(parameters) = &parameters {
+                    let name = context
+                        .string_interner
+                        .resolve_variable(parameters[index].id);
+                    context.diagnostics.report_missing_argument(location, name);
+                }
+            }
+            // Builtins expect every argument, so trailing default values
+            // are passed as well.
+            None if default_value.is_some() => {
+                arguments.push(BoundNode::fake_literal(
+                    default_value.expect("optional parameters have a default value"),
+                ));
+            }
+            None => {}
+        }
+    }
+
+    if is_missing_arguments {
+        if !has_named_arguments {
+            context.diagnostics.report_wrong_argument_count(
+                location,
+                function_type,
+                positional_count,
+            );
+        }
         BoundNode::error(location)
     } else {
         BoundNode::function_call(location, base, arguments, function_type)
     }
 }
 
+/// The parameters of a module function, if the module declares the names of
+/// its parameters.
+fn module_function_parameters(
+    member_access: &MemberAccess,
+    function_type: &FunctionType,
+    context: &mut Context,
+) -> Option<Vec<Parameter>> {
+    let &Type::Module(index) = context.type_interner.resolve(member_access.base.type_) else {
+        return None;
+    };
+    let module = context.modules[index].read().unwrap();
+    let name = context.string_interner.resolve(member_access.member);
+    let names = module
+        .try_get_function_by_name(name)?
+        .parameter_names
+        .as_ref()?;
+    Some(
+        names
+            .iter()
+            .zip(&function_type.argument_types)
+            .map(|(name, type_)| Parameter {
+                id: context.string_interner.create_or_get_variable(name),
+                type_: *type_,
+                value: None,
+            })
+            .collect(),
+    )
+}
+
+fn is_module_function(base: &BoundNode, context: &Context) -> bool {
+    let BoundNodeKind::MemberAccess(member_access) = &base.kind else {
+        return false;
+    };
+    matches!(
+        context.type_interner.resolve(member_access.base.type_),
+        Type::Module(_)
+    )
+}
+
+fn bind_children(
+    children: parser::Children,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    binder.create_scope();
+    binder.push_expected_type(TypeId::ERROR);
+    let body = children
+        .body
+        .into_iter()
+        .map(|statement| bind_node(statement, binder, context))
+        .collect();
+    binder.drop_expected_type();
+    binder.drop_scope();
+    let type_ = context
+        .type_interner
+        .get_or_intern(Type::Array(TypeId::ELEMENT));
+    BoundNode::children(body, location, type_)
+}
+
+fn bind_array_function_call(
+    name: Token,
+    function_call: parser::FunctionCall,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let name_text = name.text(&context.loaded_files).to_owned();
+    let parameter_names: &[&str] = match name_text.as_str() {
+        "range" => &["start", "end"],
+        "map" | "filter" | "flat_map" => &["array", "callback"],
+        "zip" => &["array", "other"],
+        _ => &["array"],
+    };
+    let passes_start_by_name = function_call.arguments.iter().any(|(argument, _)| {
+        matches!(&argument.kind, SyntaxNodeKind::NamedArgument(named_argument)
+            if named_argument.name.text(&context.loaded_files) == "start")
+    });
+    let Some(mut slots) =
+        match_builtin_arguments(function_call.arguments, parameter_names, context)
+    else {
+        return BoundNode::error(location);
+    };
+    // `range(end: n)` counts from zero like `range(n)`, but a single start is
+    // missing its end.
+    if name_text == "range" && matches!(slots.as_slice(), [None, Some(_)]) {
+        slots.remove(0);
+    } else if name_text == "range" && passes_start_by_name && slots[1].is_none() {
+        context.diagnostics.report_missing_argument(location, "end");
+        return BoundNode::error(location);
+    }
+    let Some(arguments) = leading_arguments(slots, parameter_names, location, context) else {
+        return BoundNode::error(location);
+    };
+
+    let (min_argument_count, argument_count) = match name_text.as_str() {
+        "range" => (1, 2),
+        "map" | "filter" | "flat_map" | "zip" => (2, 2),
+        _ => (1, 1),
+    };
+    if arguments.len() < min_argument_count || arguments.len() > argument_count {
+        context.diagnostics.report_wrong_argument_count(
+            location,
+            FunctionType {
+                min_argument_count,
+                argument_types: vec![TypeId::ERROR; argument_count],
+                return_type: TypeId::ERROR,
+            },
+            arguments.len(),
+        );
+        return BoundNode::error(location);
+    }
+
+    let expected_type = binder.currently_expected_type().unwrap_or(TypeId::ERROR);
+    let expected_element_type = context
+        .type_interner
+        .resolve(expected_type)
+        .try_as_array_ref()
+        .copied()
+        .unwrap_or(TypeId::ERROR);
+    let mut arguments = arguments.into_iter();
+    let mut bound_arguments = Vec::with_capacity(argument_count);
+
+    let return_type = if name_text == "range" {
+        for argument in arguments {
+            bound_arguments.push(bind_argument(argument, TypeId::INTEGER, binder, context));
+        }
+        context
+            .type_interner
+            .get_or_intern(Type::Array(TypeId::INTEGER))
+    } else {
+        let array_type = match name_text.as_str() {
+            "filter" | "reverse" => expected_type,
+            _ => TypeId::ERROR,
+        };
+        let array = bind_argument(arguments.next().unwrap(), array_type, binder, context);
+        let element_type = match context.type_interner.resolve(array.type_) {
+            Type::Array(element_type) => *element_type,
+            Type::String if name_text == "len" => TypeId::STRING,
+            Type::Error => return BoundNode::error(location),
+            type_ => {
+                context.diagnostics.report_cannot_convert(
+                    &context.type_interner,
+                    &context.string_interner,
+                    type_,
+                    &Type::Array(TypeId::ERROR),
+                    array.location,
+                );
+                return BoundNode::error(location);
+            }
+        };
+        bound_arguments.push(array);
+        match name_text.as_str() {
+            "len" => TypeId::INTEGER,
+            "reverse" => bound_arguments[0].type_,
+            "first" | "last" => context
+                .type_interner
+                .get_or_intern(Type::Optional(element_type)),
+            "filter" => {
+                let callback = bind_callback_argument(
+                    arguments.next().unwrap(),
+                    vec![element_type],
+                    TypeId::BOOL,
+                    binder,
+                    context,
+                );
+                bound_arguments.push(callback);
+                bound_arguments[0].type_
+            }
+            "map" => {
+                let callback = bind_callback_argument(
+                    arguments.next().unwrap(),
+                    vec![element_type],
+                    expected_element_type,
+                    binder,
+                    context,
+                );
+                let mapped_type = callback_return_type(&callback, context);
+                bound_arguments.push(callback);
+                context
+                    .type_interner
+                    .get_or_intern(Type::Array(mapped_type))
+            }
+            "flat_map" => {
+                let callback = bind_callback_argument(
+                    arguments.next().unwrap(),
+                    vec![element_type],
+                    expected_type,
+                    binder,
+                    context,
+                );
+                let mapped_type = callback_return_type(&callback, context);
+                if mapped_type != TypeId::ERROR
+                    && context
+                        .type_interner
+                        .resolve(mapped_type)
+                        .try_as_array_ref()
+                        .is_none()
+                {
+                    context.diagnostics.report_cannot_convert(
+                        &context.type_interner,
+                        &context.string_interner,
+                        context.type_interner.resolve(mapped_type),
+                        &Type::Array(TypeId::ERROR),
+                        callback.location,
+                    );
+                    return BoundNode::error(location);
+                }
+                bound_arguments.push(callback);
+                mapped_type
+            }
+            "enumerate" => {
+                let index = context.string_interner.create_or_get_variable("index");
+                let value = context.string_interner.create_or_get_variable("value");
+                let entry = context.type_interner.get_or_intern(Type::TypedDict(vec![
+                    (index, TypeId::INTEGER),
+                    (value, element_type),
+                ]));
+                context.type_interner.get_or_intern(Type::Array(entry))
+            }
+            "zip" => {
+                let other =
+                    bind_argument(arguments.next().unwrap(), TypeId::ERROR, binder, context);
+                let Some(other_element_type) = context
+                    .type_interner
+                    .resolve(other.type_)
+                    .try_as_array_ref()
+                    .copied()
+                else {
+                    if other.type_ != TypeId::ERROR {
+                        context.diagnostics.report_cannot_convert(
+                            &context.type_interner,
+                            &context.string_interner,
+                            context.type_interner.resolve(other.type_),
+                            &Type::Array(TypeId::ERROR),
+                            other.location,
+                        );
+                    }
+                    return BoundNode::error(location);
+                };
+                bound_arguments.push(other);
+                let first = context.string_interner.create_or_get_variable("first");
+                let second = context.string_interner.create_or_get_variable("second");
+                let entry = context.type_interner.get_or_intern(Type::TypedDict(vec![
+                    (first, element_type),
+                    (second, other_element_type),
+                ]));
+                context.type_interner.get_or_intern(Type::Array(entry))
+            }
+            _ => unreachable!("Unknown array function {name_text}"),
+        }
+    };
+
+    if bound_arguments.iter().any(|a| a.type_ == TypeId::ERROR) {
+        return BoundNode::error(location);
+    }
+    let function_type = FunctionType {
+        min_argument_count,
+        argument_types: bound_arguments.iter().map(|a| a.type_).collect(),
+        return_type,
+    };
+    let variable = Variable {
+        id: context.string_interner.create_or_get_variable(&name_text),
+        definition: Location::zero(),
+        type_: context
+            .type_interner
+            .get_or_intern(Type::Function(function_type.clone())),
+    };
+    let base = BoundNode::variable_reference(name, &variable);
+    BoundNode::function_call(location, base, bound_arguments, function_type)
+}
+
+/// Binds `slide("name")`, which returns the index, name and title of a slide,
+/// and `toc()`, which lists the sections and slides in a label. Both need the
+/// titles of slides further down in the presentation, see
+/// [`BoundAst::needs_slide_titles`].
+fn bind_presentation_function_call(
+    name: Token,
+    function_call: parser::FunctionCall,
+    location: Location,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let name_text = name.text(&context.loaded_files).to_owned();
+    let (argument_types, return_type) = match name_text.as_str() {
+        "slide" => {
+            let fields = ["index", "name", "title"]
+                .map(|field| context.string_interner.create_or_get_variable(field));
+            let slide_type = context.type_interner.get_or_intern(Type::TypedDict(vec![
+                (fields[0], TypeId::INTEGER),
+                (fields[1], TypeId::STRING),
+                (fields[2], TypeId::STRING),
+            ]));
+            (vec![TypeId::STRING], slide_type)
+        }
+        "toc" => (Vec::new(), context.type_interner.get_or_intern(Type::Label)),
+        _ => unreachable!("Unknown presentation function {name_text}"),
+    };
+    let function_type = FunctionType {
+        min_argument_count: argument_types.len(),
+        argument_types,
+        return_type,
+    };
+    let parameter_names: &[&str] = match name_text.as_str() {
+        "slide" => &["name"],
+        _ => &[],
+    };
+    let Some(slots) = match_builtin_arguments(function_call.arguments, parameter_names, context)
+    else {
+        return BoundNode::error(location);
+    };
+    let Some(syntax_arguments) = leading_arguments(slots, parameter_names, location, context)
+    else {
+        return BoundNode::error(location);
+    };
+    if syntax_arguments.len() != function_type.argument_types.len() {
+        context.diagnostics.report_wrong_argument_count(
+            location,
+            function_type,
+            syntax_arguments.len(),
+        );
+        return BoundNode::error(location);
+    }
+    let mut arguments = Vec::with_capacity(function_type.argument_types.len());
+    for (argument, type_) in syntax_arguments
+        .into_iter()
+        .zip(&function_type.argument_types)
+    {
+        let argument = bind_argument(argument, *type_, binder, context);
+        if argument.type_ == TypeId::ERROR {
+            return BoundNode::error(location);
+        }
+        if name_text == "slide"
+            && let Some(Value::String(slide_name)) = &argument.constant_value
+            && !binder.slide_names.contains(slide_name)
+        {
+            context
+                .diagnostics
+                .report_no_slide_named(argument.location, slide_name);
+            return BoundNode::error(location);
+        }
+        arguments.push(argument);
+    }
+    binder.needs_slide_titles = true;
+    let variable = Variable {
+        id: context.string_interner.create_or_get_variable(&name_text),
+        definition: Location::zero(),
+        type_: context
+            .type_interner
+            .get_or_intern(Type::Function(function_type.clone())),
+    };
+    let base = BoundNode::variable_reference(name, &variable);
+    BoundNode::function_call(location, base, arguments, function_type)
+}
+
+/// Sorts the arguments of a builtin, which is bound by hand, into the slots
+/// of its parameters, so that they can be passed by name as well. Positional
+/// arguments beyond the parameters get slots of their own, so that the
+/// caller can report the wrong argument count. Returns `None` after reporting
+/// a wrongly named argument.
+fn match_builtin_arguments(
+    arguments: Vec<(SyntaxNode, Option<Token>)>,
+    parameter_names: &[&str],
+    context: &mut Context,
+) -> Option<Vec<Option<SyntaxNode>>> {
+    let mut slots: Vec<Option<SyntaxNode>> = vec![None; parameter_names.len()];
+    let mut positional_count = 0;
+    let mut has_named_arguments = false;
+    let mut has_errors = false;
+    for (argument, _) in arguments {
+        let SyntaxNodeKind::NamedArgument(named_argument) = argument.kind else {
+            if has_named_arguments {
+                context
+                    .diagnostics
+                    .report_positional_after_named_argument(argument.location);
+                has_errors = true;
+            }
+            match slots.get_mut(positional_count) {
+                Some(slot) => *slot = Some(argument),
+                None => slots.push(Some(argument)),
+            }
+            positional_count += 1;
+            continue;
+        };
+        has_named_arguments = true;
+        let name = named_argument.name.text(&context.loaded_files);
+        let Some(index) = parameter_names.iter().position(|p| *p == name) else {
+            context.diagnostics.report_unknown_argument_name(
+                named_argument.name.location,
+                name,
+                parameter_names.to_vec(),
+            );
+            has_errors = true;
+            continue;
+        };
+        if slots[index].is_some() || index < positional_count {
+            context
+                .diagnostics
+                .report_duplicate_argument(named_argument.name.location, name);
+            has_errors = true;
+            continue;
+        }
+        slots[index] = Some(*named_argument.value);
+    }
+    (!has_errors).then_some(slots)
+}
+
+/// The arguments up to the first missing one. Arguments after a missing one
+/// can only be passed by name, so the missing argument is reported.
+fn leading_arguments(
+    slots: Vec<Option<SyntaxNode>>,
+    parameter_names: &[&str],
+    location: Location,
+    context: &mut Context,
+) -> Option<Vec<SyntaxNode>> {
+    let count = slots.iter().take_while(|slot| slot.is_some()).count();
+    if slots[count..].iter().any(Option::is_some) {
+        context
+            .diagnostics
+            .report_missing_argument(location, parameter_names[count]);
+        return None;
+    }
+    Some(slots.into_iter().flatten().collect())
+}
+
+fn bind_argument(
+    argument: SyntaxNode,
+    type_: TypeId,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    binder.push_expected_type(type_);
+    let argument = bind_node(argument, binder, context);
+    binder.drop_expected_type();
+    bind_conversion(argument, type_, ConversionKind::Implicit, binder, context)
+}
+
+/// Binds a function passed to an array function. If `return_type` is
+/// [`TypeId::ERROR`] it is taken from the function itself.
+fn bind_callback_argument(
+    argument: SyntaxNode,
+    argument_types: Vec<TypeId>,
+    return_type: TypeId,
+    binder: &mut Binder,
+    context: &mut Context,
+) -> BoundNode {
+    let expected_type = context
+        .type_interner
+        .get_or_intern(Type::Function(FunctionType {
+            min_argument_count: argument_types.len(),
+            argument_types: argument_types.clone(),
+            return_type,
+        }));
+    binder.push_expected_type(expected_type);
+    let callback = bind_node(argument, binder, context);
+    binder.drop_expected_type();
+    let return_type = if return_type == TypeId::ERROR {
+        callback_return_type(&callback, context)
+    } else {
+        return_type
+    };
+    let target = context
+        .type_interner
+        .get_or_intern(Type::Function(FunctionType {
+            min_argument_count: argument_types.len(),
+            argument_types,
+            return_type,
+        }));
+    bind_conversion(callback, target, ConversionKind::Implicit, binder, context)
+}
+
+fn callback_return_type(callback: &BoundNode, context: &Context) -> TypeId {
+    context
+        .type_interner
+        .resolve(callback.type_)
+        .try_as_function_ref()
+        .map_or(TypeId::ERROR, |f| f.return_type)
+}
+
 fn bind_assignment_statement(
     assignment_statement: parser::AssignmentStatement,
     location: Location,
@@ -2372,18 +4724,86 @@ fn bind_styling_statement(
     context: &mut Context,
 ) -> BoundNode {
     let type_ = styling_statement.type_.text(&context.loaded_files);
-    let (type_, members) = match type_ {
+    let mut custom_element = None;
+    let mut properties = Vec::new();
+    let (type_, members): (_, Vec<_>) = match type_ {
         "Label" | "Slide" | "Image" => (
             StylingType::try_from(type_).unwrap(),
-            globals::find_members_by_name(type_),
+            globals::find_members_by_name(type_)
+                .map(|(name, type_)| (name.to_owned(), type_))
+                .collect(),
+        ),
+        // The children of grids and flexes are passed to their constructors,
+        // so they cannot be styled.
+        "Grid" | "Flex" => (
+            StylingType::try_from(type_).unwrap(),
+            globals::find_members_by_name(type_)
+                .filter(|(name, _)| *name != "children")
+                .map(|(name, type_)| (name.to_owned(), type_))
+                .collect(),
         ),
         _ => {
-            context
-                .diagnostics
-                .report_unexpected_styling_type(type_, styling_statement.type_.location);
-            return BoundNode::error(styling_statement.type_.location);
+            // Elements can be referred to by their name or their type name.
+            let type_name = context
+                .string_interner
+                .create_or_get(&type_.to_case(convert_case::Case::Pascal));
+            let Some((element_type, element_properties)) = binder
+                .look_up_type_by_name(type_name)
+                .and_then(|t| Some((t, binder.element_properties.get(&t)?)))
+            else {
+                context
+                    .diagnostics
+                    .report_unexpected_styling_type(type_, styling_statement.type_.location);
+                return BoundNode::error(styling_statement.type_.location);
+            };
+            custom_element = context
+                .type_interner
+                .resolve(element_type)
+                .try_as_custom_element_ref()
+                .map(|(type_name, _)| type_name.clone());
+            properties = element_properties.iter().map(|&(id, _)| id).collect();
+            let members = globals::find_members_by_name("Element")
+                .map(|(name, type_)| (name.to_owned(), type_))
+                .chain(element_properties.iter().map(|&(id, type_)| {
+                    (
+                        context.string_interner.resolve_variable(id).to_owned(),
+                        context.type_interner.resolve(type_).clone(),
+                    )
+                }))
+                .collect();
+            (StylingType::CustomElement, members)
         }
     };
+    // Stylings can only extend stylings for the same element.
+    let element = custom_element.clone().unwrap_or_else(|| {
+        styling_statement
+            .type_
+            .text(&context.loaded_files)
+            .to_owned()
+    });
+
+    let base = styling_statement.optional_extends.and_then(|(_, base)| {
+        let text = base.text(&context.loaded_files);
+        let id = context.string_interner.create_or_get_variable(text);
+        match binder.stylings.get(&id) {
+            Some(base_element) if base_element == &element => Some(id),
+            Some(base_element) => {
+                context.diagnostics.report_incompatible_styling_base(
+                    base.location,
+                    text,
+                    base_element,
+                    &element,
+                );
+                None
+            }
+            None => {
+                context
+                    .diagnostics
+                    .report_unknown_styling(base.location, text);
+                None
+            }
+        }
+    });
 
     binder.create_scope();
 
@@ -2419,9 +4839,9 @@ fn bind_styling_statement(
 
     // Bind name last to check the body for errors!
     let styling_type = context.type_interner.get_or_intern(Type::Styling);
-    let name = &context.loaded_files[styling_statement.name.location];
+    let is_default = &context.loaded_files[styling_statement.name.location] == "default";
 
-    let Some(name) = (if name == "default" {
+    let Some(name) = (if is_default {
         Some(context.string_interner.create_or_get_variable("default"))
     } else {
         binder.expect_register_variable_token(
@@ -2433,5 +4853,17 @@ fn bind_styling_statement(
     }) else {
         return BoundNode::error(styling_statement.name.location);
     };
-    BoundNode::styling_statement(styling_statement, location, name, type_, body)
+    if !is_default {
+        binder.stylings.insert(name, element);
+    }
+    BoundNode::styling_statement(
+        styling_statement,
+        location,
+        name,
+        type_,
+        custom_element,
+        properties,
+        base,
+        body,
+    )
 }
@@ -2,8 +2,9 @@
 
 use constcat::concat_slices;
 use slides_rs_core::{
-    BaseElementStyling, FlexStyling, GridEntry, GridStyling, HorizontalAlignment, ImageStyling,
-    LabelStyling, ObjectFit, SlideStyling, SlidesEnum, TextAlign, TextStyling, VerticalAlignment,
+    BaseElementStyling, FlexDirection, FlexStyling, FontStyle, GridEntry, HorizontalAlignment,
+    ImageStyling, LabelStyling, ObjectFit, SlideStyling, SlidesEnum, TextAlign, TextDecoration,
+    TextStyling, TextTransform, VerticalAlignment, WhiteSpace,
 };
 
 include!(concat!(env!("OUT_DIR"), "/global_functions.rs"));
@@ -25,11 +26,42 @@ impl EnumDeclaration {
     }
 }
 
-pub const ENUMS: [EnumDeclaration; 4] = [
+/// Functions over arrays of any element type. Their signatures cannot be
+/// expressed in [`FUNCTIONS`], so they are checked by the binder and executed
+/// by the evaluator directly.
+pub const ARRAY_FUNCTIONS: [&str; 10] = [
+    "map",
+    "filter",
+    "flat_map",
+    "enumerate",
+    "zip",
+    "len",
+    "range",
+    "reverse",
+    "first",
+    "last",
+];
+
+/// Functions that need to know every slide of the presentation. They are
+/// checked by the binder and executed by the evaluator directly.
+pub const PRESENTATION_FUNCTIONS: [&str; 2] = ["slide", "toc"];
+
+pub const ENUMS: [EnumDeclaration; 10] = [
     EnumDeclaration::rename::<ObjectFit>("ObjectFit", Type::ObjectFit),
     EnumDeclaration::rename::<HorizontalAlignment>("HAlign", Type::HAlign),
     EnumDeclaration::rename::<VerticalAlignment>("VAlign", Type::VAlign),
     EnumDeclaration::rename::<TextAlign>("TextAlign", Type::TextAlign),
+    EnumDeclaration::rename::<FontStyle>("FontStyle", Type::FontStyle),
+    EnumDeclaration::rename::<TextDecoration>("TextDecoration", Type::TextDecoration),
+    EnumDeclaration::rename::<TextTransform>("TextTransform", Type::TextTransform),
+    EnumDeclaration::rename::<WhiteSpace>("WhiteSpace", Type::WhiteSpace),
+    EnumDeclaration::rename::<FlexDirection>("FlexDirection", Type::FlexDirection),
+    // Fixed sizes are created with `background_size`.
+    EnumDeclaration {
+        name: "BackgroundSize",
+        type_: Type::BackgroundSize,
+        variants: &["Cover", "Contain"],
+    },
 ];
 
 #[derive(Debug)]
@@ -85,10 +117,14 @@ pub const MEMBERS: [MemberDeclarations; 16] = [
     default_element!("Slide"),
     MemberDeclarations {
         name: "Slide",
-        members_names: &["steps"],
-        members_rust_types: &["usize"],
+        members_names: &["steps", "numbered"],
+        members_rust_types: &["usize", "bool"],
+    },
+    MemberDeclarations {
+        name: "Grid",
+        members_names: &["columns", "rows"],
+        members_rust_types: &["GridCellSizes", "GridCellSizes"],
     },
-    MemberDeclarations::rename::<GridStyling>("Grid"),
     default_element!("Grid"),
     MemberDeclarations {
         name: "Grid",
@@ -115,6 +151,8 @@ pub(crate) fn normalize_type_name(mut name: &str) -> &str {
     name
 }
 
+/// Finds the members, which can be assigned to. Member functions, like
+/// `add` of grids, are skipped.
 pub(crate) fn find_members_by_name(
     name: &str,
 ) -> impl Iterator<Item = (&'static str, Type)> + 'static {
@@ -126,9 +164,12 @@ pub(crate) fn find_members_by_name(
             m.members_names
                 .iter()
                 .copied()
-                .zip(m.members_rust_types.iter().map(|r| {
-                    Type::from_rust_string(normalize_type_name(r))
-                        .unwrap_or_else(|| panic!("{r} should exist"))
-                }))
+                .zip(m.members_rust_types.iter())
+                .filter(|(_, r)| !r.starts_with('#'))
+                .map(|(n, r)| {
+                    let type_ = Type::from_rust_string(normalize_type_name(r))
+                        .unwrap_or_else(|| panic!("{r} should exist"));
+                    (n, type_)
+                })
         })
 }
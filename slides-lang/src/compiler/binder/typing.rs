@@ -25,7 +25,7 @@ impl FunctionType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct TypeId(usize);
 
 impl TypeId {
@@ -44,6 +44,8 @@ impl TypeId {
     pub const STYLE_UNIT: TypeId = TypeId(17);
     pub const ELEMENT: TypeId = TypeId(19);
     pub const ANIMATION: TypeId = TypeId(28);
+    pub const COLOR_STOP: TypeId = TypeId(31);
+    pub const BORDER: TypeId = TypeId(32);
 
     pub unsafe fn from_raw(raw: usize) -> Self {
         Self(raw)
@@ -72,6 +74,8 @@ impl TypeInterner {
         debug_assert_eq!(result.get_or_intern(Type::StyleUnit), TypeId::STYLE_UNIT);
         debug_assert_eq!(result.get_or_intern(Type::Element), TypeId::ELEMENT);
         debug_assert_eq!(result.get_or_intern(Type::Animation), TypeId::ANIMATION);
+        debug_assert_eq!(result.get_or_intern(Type::ColorStop), TypeId::COLOR_STOP);
+        debug_assert_eq!(result.get_or_intern(Type::Border), TypeId::BORDER);
         result
     }
 
@@ -90,6 +94,15 @@ impl TypeInterner {
         self.types.get(id.0).expect("TypeIds are always valid")
     }
 
+    pub fn enum_variants(&self, enum_type: &Type) -> Option<&[String]> {
+        self.types.iter().find_map(|t| match t {
+            Type::EnumDefinition(base, variants) if **base == *enum_type => {
+                Some(variants.as_slice())
+            }
+            _ => None,
+        })
+    }
+
     pub fn resolve_types<const N: usize>(&self, target: [TypeId; N]) -> [&Type; N] {
         target.map(|t| self.types.get(t.0).expect("TypeIds are always valid"))
     }
@@ -271,6 +284,19 @@ impl TypeInterner {
             Type::Position => "Position".into(),
             Type::Struct(struct_data) => string_interner.resolve_variable(struct_data.name).into(),
             Type::Module(_) => "module".into(),
+            Type::Angle => "Angle".into(),
+            Type::ColorStop => "ColorStop".into(),
+            Type::Border => "Border".into(),
+            Type::BoxShadow => "BoxShadow".into(),
+            Type::Transform => "Transform".into(),
+            Type::FontStyle => "FontStyle".into(),
+            Type::TextDecoration => "TextDecoration".into(),
+            Type::TextTransform => "TextTransform".into(),
+            Type::WhiteSpace => "WhiteSpace".into(),
+            Type::FlexDirection => "FlexDirection".into(),
+            Type::BackgroundSize => "BackgroundSize".into(),
+            Type::GridCellSizes => "GridCellSizes".into(),
+            Type::FontSize => "FontSize".into(),
         }
     }
 
@@ -404,14 +430,30 @@ pub enum Type {
     Animation,
     Position,
     Module(ModuleIndex),
+    Angle,
+    ColorStop,
+    Border,
+    BoxShadow,
+    Transform,
+    FontStyle,
+    TextDecoration,
+    TextTransform,
+    WhiteSpace,
+    FlexDirection,
+    BackgroundSize,
+    GridCellSizes,
+    FontSize,
 }
 
 impl Type {
     pub fn get_available_conversions(&self, kind: ConversionKind) -> &'static [Type] {
         match kind {
             ConversionKind::Implicit => match self {
-                Type::Integer => &[Type::Float],
-                Type::Color => &[Type::Background],
+                Type::Integer => &[Type::Float, Type::Angle, Type::FontSize],
+                Type::Float => &[Type::Angle, Type::FontSize],
+                Type::StyleUnit => &[Type::FontSize],
+                Type::FontSize => &[Type::StyleUnit],
+                Type::Color => &[Type::Background, Type::ColorStop],
                 Type::Label | Type::Image | Type::CustomElement(_, _) => &[Type::Element],
                 _ => &[],
             },
@@ -420,7 +462,9 @@ impl Type {
                 _ => &[],
             },
             ConversionKind::ToString => match self {
-                Type::Float | Type::Integer | Type::String | Type::Path => &[Type::String],
+                Type::Float | Type::Integer | Type::Bool | Type::String | Type::Path => {
+                    &[Type::String]
+                }
                 _ => &[],
             },
         }
@@ -635,6 +679,34 @@ impl Type {
             Some(Self::Flex)
         } else if konst::eq_str(rust_string, "Position") {
             Some(Self::Position)
+        } else if konst::eq_str(rust_string, "Angle") {
+            Some(Self::Angle)
+        } else if konst::eq_str(rust_string, "ColorStop") {
+            Some(Self::ColorStop)
+        } else if konst::eq_str(rust_string, "ColorStopArray") {
+            Some(Self::Array(TypeId::COLOR_STOP))
+        } else if konst::eq_str(rust_string, "Border") {
+            Some(Self::Border)
+        } else if konst::eq_str(rust_string, "BoxShadow") {
+            Some(Self::BoxShadow)
+        } else if konst::eq_str(rust_string, "Transform") {
+            Some(Self::Transform)
+        } else if konst::eq_str(rust_string, "FontStyle") {
+            Some(Self::FontStyle)
+        } else if konst::eq_str(rust_string, "TextDecoration") {
+            Some(Self::TextDecoration)
+        } else if konst::eq_str(rust_string, "TextTransform") {
+            Some(Self::TextTransform)
+        } else if konst::eq_str(rust_string, "WhiteSpace") {
+            Some(Self::WhiteSpace)
+        } else if konst::eq_str(rust_string, "FlexDirection") {
+            Some(Self::FlexDirection)
+        } else if konst::eq_str(rust_string, "BackgroundSize") {
+            Some(Self::BackgroundSize)
+        } else if konst::eq_str(rust_string, "GridCellSizes") {
+            Some(Self::GridCellSizes)
+        } else if konst::eq_str(rust_string, "FontSize") {
+            Some(Self::FontSize)
         } else {
             None
         }
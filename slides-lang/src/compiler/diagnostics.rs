@@ -1,4 +1,4 @@
-use std::{error::Error, fmt::Display};
+use std::{error::Error, fmt::Display, path::Path};
 
 use super::{
     binder::{Variable, typing::Type},
@@ -206,6 +206,10 @@ impl Diagnostics {
         );
     }
 
+    pub(crate) fn report_division_by_zero(&mut self, location: Location) {
+        self.report_error("Division by zero".into(), location);
+    }
+
     pub(crate) fn report_field_does_not_exist(
         &mut self,
         location: Location,
@@ -251,4 +255,344 @@ impl Diagnostics {
             }
         }
     }
+
+    pub(crate) fn report_duplicate_enum_variant(
+        &mut self,
+        location: Location,
+        enum_name: &str,
+        variant: &str,
+    ) {
+        self.report_error(
+            format!("Variant {variant} is declared more than once in enum {enum_name}."),
+            location,
+        );
+    }
+
+    pub(crate) fn report_redeclaration_of_type(&mut self, location: Location, name: &str) {
+        self.report_error(format!("Unallowed redeclaration of type {name}."), location);
+    }
+
+    pub(crate) fn report_cannot_match_on_type(
+        &mut self,
+        type_interner: &TypeInterner,
+        string_interner: &StringInterner,
+        type_: &Type,
+        location: Location,
+    ) {
+        let type_ = type_interner.to_simple_string(type_, string_interner);
+        self.report_error(
+            format!("Cannot match on type {type_}, only enums can be matched"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_expected_optional(
+        &mut self,
+        type_interner: &TypeInterner,
+        string_interner: &StringInterner,
+        type_: &Type,
+        location: Location,
+    ) {
+        let type_ = type_interner.to_simple_string(type_, string_interner);
+        self.report_error(
+            format!("Expected an optional value, but found a value of type {type_}"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unknown_enum_variant(
+        &mut self,
+        location: Location,
+        type_interner: &TypeInterner,
+        string_interner: &StringInterner,
+        enum_type: &Type,
+        variant: &str,
+    ) {
+        let enum_type = type_interner.to_simple_string(enum_type, string_interner);
+        self.report_error(
+            format!("Enum {enum_type} has no variant named {variant}"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unreachable_match_arm(&mut self, location: Location, variant: &str) {
+        self.report_error(
+            format!("Match arm {variant} is unreachable, since it is already covered"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_non_exhaustive_match(&mut self, location: Location, missing: &[String]) {
+        self.report_error(
+            format!(
+                "Match is not exhaustive, missing variants: {}",
+                missing.join(", ")
+            ),
+            location,
+        );
+    }
+
+    pub(crate) fn report_positional_after_named_argument(&mut self, location: Location) {
+        self.report_error(
+            "Positional arguments cannot follow named arguments".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_named_arguments_not_supported(&mut self, location: Location, name: &str) {
+        self.report_error(
+            format!("Cannot pass {name} by name, this function has no named parameters"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_builtin_used_as_value(&mut self, location: Location, name: &str) {
+        self.report_error(
+            format!("Builtin {name} can only be called, use a lambda like `x => {name}(x)`"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_invalid_grid_cell_sizes(&mut self, location: Location, message: String) {
+        self.report_error(message, location);
+    }
+
+    pub(crate) fn report_module_named_arguments_not_supported(
+        &mut self,
+        location: Location,
+        name: &str,
+    ) {
+        self.report_error(
+            format!("Cannot pass {name} by name, this module does not name its parameters"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unknown_argument_name(
+        &mut self,
+        location: Location,
+        name: &str,
+        parameters: Vec<&str>,
+    ) {
+        let diagnostic = self.report_error(format!("No parameter named {name} found"), location);
+        if !parameters.is_empty() {
+            diagnostic.add_hint(
+                format!("Available parameters are {}", parameters.join(", ")),
+                location,
+            );
+        }
+    }
+
+    pub(crate) fn report_duplicate_argument(&mut self, location: Location, name: &str) {
+        self.report_error(
+            format!("Argument {name} was already passed before"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_missing_argument(&mut self, location: Location, name: &str) {
+        self.report_error(format!("Missing argument {name}"), location);
+    }
+
+    pub(crate) fn report_cannot_infer_parameter_type(&mut self, location: Location, name: &str) {
+        let diagnostic = self.report_error(
+            format!("Cannot infer the type of parameter {name}"),
+            location,
+        );
+        diagnostic.add_hint(
+            format!("Annotate the type explicitly, e.g. {name}: String"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unclosed_format_placeholder(&mut self, location: Location) {
+        let diagnostic = self.report_error(
+            "Placeholder in format string is never closed".into(),
+            location,
+        );
+        diagnostic.add_hint("Escape the brace as \\{ to use it as text".into(), location);
+    }
+
+    pub(crate) fn report_unmatched_closing_brace(&mut self, location: Location) {
+        let diagnostic = self.report_error("Unmatched } in format string".into(), location);
+        diagnostic.add_hint("Escape the brace as \\} to use it as text".into(), location);
+    }
+
+    pub(crate) fn report_invalid_format_spec(
+        &mut self,
+        type_interner: &TypeInterner,
+        string_interner: &StringInterner,
+        type_: &Type,
+        spec: &str,
+        location: Location,
+    ) {
+        let type_ = type_interner.to_simple_string(type_, string_interner);
+        let diagnostic = self.report_error(
+            format!("Invalid format spec {spec} for value of type {type_}"),
+            location,
+        );
+        diagnostic.add_hint(
+            "Use e.g. 4 for a minimum width, 02 for zero padding or .2 for decimal places. \
+             Zero padding and decimal places only work for numbers"
+                .into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_expected_constant(&mut self, name: &str, location: Location) {
+        let diagnostic = self.report_error(format!("{name} has to be a constant"), location);
+        diagnostic.add_hint(
+            "Page numbers are assigned before any slide is evaluated".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unknown_slide(&mut self, location: Location, name: &str) {
+        let diagnostic = self.report_error(format!("No slide named {name} found"), location);
+        diagnostic.add_hint(
+            "Only slides declared before this one can be extended".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_no_slide_named(&mut self, location: Location, name: &str) {
+        self.report_error(format!("There is no slide named {name}"), location);
+    }
+
+    pub(crate) fn report_unknown_styling(&mut self, location: Location, name: &str) {
+        let diagnostic = self.report_error(format!("No styling named {name} found"), location);
+        diagnostic.add_hint(
+            "Only stylings declared before this one can be extended".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_incompatible_styling_base(
+        &mut self,
+        location: Location,
+        name: &str,
+        base_element: &str,
+        element: &str,
+    ) {
+        self.report_error(
+            format!("Styling {name} is for {base_element} and cannot be extended by a styling for {element}"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_remove_outside_of_slide(&mut self, location: Location) {
+        self.report_error(
+            "Elements can only be removed inside of slides".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_property_outside_of_element(&mut self, location: Location) {
+        self.report_error(
+            "Properties can only be declared in the body of an element".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unknown_use(&mut self, location: Location, kind: &str) {
+        let diagnostic = self.report_error(format!("Cannot use {kind}"), location);
+        diagnostic.add_hint(
+            "Only themes can be used, like `use theme \"dark\";`".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_theme_already_used(&mut self, location: Location) {
+        self.report_error(
+            "A presentation can only use a single theme".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_unknown_theme(&mut self, location: Location, name: &str, path: &Path) {
+        self.report_error(
+            format!("No theme named {name} found at {}", path.display()),
+            location,
+        );
+    }
+
+    pub(crate) fn report_invalid_theme_name(&mut self, location: Location, name: &str) {
+        self.report_error(
+            format!("Theme names cannot be paths, but found {name}"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_slide_in_theme(&mut self, location: Location) {
+        self.report_error("Themes cannot contain slides or sections".into(), location);
+    }
+
+    pub(crate) fn report_unknown_layout(&mut self, location: Location, name: &str) {
+        self.report_error(format!("No layout named {name} found"), location);
+    }
+
+    pub(crate) fn report_unfilled_placeholder(
+        &mut self,
+        location: Location,
+        layout: &str,
+        placeholder: &str,
+    ) {
+        let diagnostic = self.report_error(
+            format!("Placeholder {placeholder} of layout {layout} is not filled"),
+            location,
+        );
+        diagnostic.add_hint(
+            format!("Declare an element called {placeholder} on this slide"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_no_children_parameter(&mut self, location: Location) {
+        let diagnostic = self.report_error("Function does not accept children".into(), location);
+        diagnostic.add_hint(
+            "Add a parameter of type Array:Element to receive the children".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_children_already_passed(&mut self, location: Location, name: &str) {
+        self.report_error(
+            format!("Children would be passed as {name}, which was already passed before"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_invalid_token_statement(&mut self, location: Location) {
+        let diagnostic = self.report_error("Tokens can only contain declarations".into(), location);
+        diagnostic.add_hint(
+            "Declare a token like `let accent = c\"#2962ff\";`".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_invalid_token_type(&mut self, location: Location, type_: &str) {
+        self.report_error(
+            format!("Tokens can only be colors, style units or fonts, but found {type_}"),
+            location,
+        );
+    }
+
+    pub(crate) fn report_invalid_number(&mut self, location: Location, text: &str) {
+        self.report_error(format!("{text} is not a valid number"), location);
+    }
+
+    pub(crate) fn report_unknown_unit(&mut self, location: Location, unit: &str) {
+        let diagnostic = self.report_error(format!("Unknown unit {unit}"), location);
+        diagnostic.add_hint(
+            "Use one of px, pt, %, sw, sh, em, rem, vw, vh, cm, mm, in, deg or turn".into(),
+            location,
+        );
+    }
+
+    pub(crate) fn report_separated_unit(&mut self, location: Location) {
+        self.report_error(
+            "Units have to follow their number without any space".into(),
+            location,
+        );
+    }
 }
@@ -6,8 +6,8 @@ use std::{
 
 use index_map::IndexMap;
 use slides_rs_core::{
-    DynamicElementStyling, FilePlacement, ImageStyling, LabelStyling, Slide, SlideStyling,
-    TextStyling,
+    DynamicElementStyling, Element, ElementStyling, FilePlacement, FlexStyling, Font, GridStyling,
+    ImageStyling, LabelStyling, Slide, SlideStyling, StyleUnit, TokenName, TokenValue,
 };
 
 use super::binder::{BoundAst, BoundError, BoundNode, BoundNodeKind, StylingType, typing::TypeId};
@@ -49,6 +49,12 @@ impl Scope {
         self.variables.insert(name, index);
     }
 
+    /// The value stays in the scope, but is no longer reachable and therefore
+    /// not added to the slide.
+    fn remove_variable(&mut self, name: VariableId) {
+        self.variables.remove(&name);
+    }
+
     fn get_variable(&self, name: VariableId) -> Option<&Value> {
         let index = self.variables.get(&name)?;
         self.values.get(*index)
@@ -89,6 +95,103 @@ impl Exception {
     }
 }
 
+/// What is known about a slide before it is evaluated, so that templates can
+/// refer to slides further down in the presentation.
+#[derive(Debug, Clone)]
+struct SlideInfo {
+    name: String,
+    /// The first line of the label called `title` on the slide. Until the
+    /// slide has been evaluated, this is the name of the slide.
+    title: String,
+    /// Index into [`Outline::sections`].
+    section: Option<usize>,
+    /// Only slides which are `numbered` get a page number, starting at 1.
+    page_number: Option<usize>,
+}
+
+/// The sections and slides of a presentation in order of their appearance.
+#[derive(Debug, Clone, Default)]
+struct Outline {
+    slides: Vec<SlideInfo>,
+    sections: Vec<String>,
+}
+
+impl Outline {
+    fn collect(ast: &BoundAst, context: &mut Context) -> Outline {
+        let numbered = context.string_interner.create_or_get_variable("numbered");
+        let mut outline = Outline::default();
+        let mut page_count = 0;
+        for statement in &ast.statements {
+            match &statement.kind {
+                BoundNodeKind::SectionStatement(title) => outline.sections.push(title.clone()),
+                BoundNodeKind::SlideStatement(slide_statement) => {
+                    let name = context
+                        .string_interner
+                        .resolve_variable(slide_statement.name)
+                        .to_owned();
+                    let page_number =
+                        SlideInfo::is_numbered(&slide_statement.body, numbered).then(|| {
+                            page_count += 1;
+                            page_count
+                        });
+                    outline.slides.push(SlideInfo {
+                        title: name.clone(),
+                        name,
+                        section: outline.sections.len().checked_sub(1),
+                        page_number,
+                    });
+                }
+                _ => {}
+            }
+        }
+        outline
+    }
+}
+
+impl SlideInfo {
+    /// The binder made sure that `numbered` is only ever assigned constants.
+    fn is_numbered(body: &[BoundNode], numbered: VariableId) -> bool {
+        body.iter()
+            .filter_map(|statement| statement.kind.try_as_assignment_statement_ref())
+            .filter(|assignment| {
+                assignment
+                    .lhs
+                    .kind
+                    .try_as_variable_reference_ref()
+                    .is_some_and(|variable| variable.id == numbered)
+            })
+            .filter_map(|assignment| assignment.value.constant_value.as_ref())
+            .last()
+            .is_none_or(|value| !matches!(value, value::Value::Bool(false)))
+    }
+
+    fn title_of(slide: &Slide) -> Option<String> {
+        slide.elements().iter().find_map(|element| {
+            let Element::Label(label) = element else {
+                return None;
+            };
+            let label = label.read().unwrap();
+            if label.name() != "title" {
+                return None;
+            }
+            let title = label.text().trim().lines().next().unwrap_or_default();
+            Some(title.trim_start_matches('#').trim().to_owned())
+        })
+    }
+}
+
+/// How a user defined element with properties was created, so that it can be
+/// evaluated again, when one of its properties changes.
+#[derive(Debug, Clone)]
+struct ElementSource {
+    function: UserFunctionValue,
+    arguments: Vec<Value>,
+    properties: Vec<(VariableId, Value)>,
+    /// Styling set on the element itself, which takes precedence over the
+    /// styling set by its body.
+    styling: slides_rs_core::BaseElementStyling,
+}
+
 pub struct Evaluator {
     scopes: Vec<Scope>,
     slide: Option<Slide>,
@@ -96,9 +199,20 @@ pub struct Evaluator {
     exception: Option<Exception>,
     default_template: Option<value::UserFunctionValue>,
     return_value: Option<Value>,
+    outline: Outline,
+    is_first_pass: bool,
+    /// The properties of every user defined element type.
+    element_properties: HashMap<TypeId, Vec<VariableId>>,
+    /// Keyed by the raw id of the created element.
+    element_sources: HashMap<usize, ElementSource>,
+    /// Properties set by stylings of user defined elements, keyed by the name
+    /// of the styling.
+    styling_properties: HashMap<String, Vec<(VariableId, Value)>>,
+    /// Whether the statements of the theme are evaluated.
+    is_theme: bool,
 }
 impl Evaluator {
-    fn new() -> Self {
+    fn new(outline: Outline, is_first_pass: bool) -> Self {
         Self {
             scopes: vec![Scope::global()],
             slide: None,
@@ -106,6 +220,20 @@ impl Evaluator {
             exception: None,
             default_template: None,
             return_value: None,
+            outline,
+            is_first_pass,
+            element_properties: HashMap::new(),
+            element_sources: HashMap::new(),
+            styling_properties: HashMap::new(),
+            is_theme: false,
+        }
+    }
+
+    /// Exceptions of the first pass are not printed, since the second pass
+    /// runs into them again.
+    fn print_exception(&self, exception: Exception, loaded_files: &crate::Files) {
+        if !self.is_first_pass {
+            exception.print(loaded_files);
         }
     }
 
@@ -184,8 +312,28 @@ pub fn create_presentation_from_ast(
     ast: BoundAst,
     context: &mut Context,
 ) -> slides_rs_core::Result<()> {
-    let mut evaluator = Evaluator::new();
+    let mut outline = Outline::collect(&ast, context);
+    if ast.needs_slide_titles {
+        // Evaluate everything once to learn the titles of all slides and then
+        // throw the resulting presentation away. Module functions are
+        // skipped in this pass.
+        let snapshot = context.presentation.get_cloned().unwrap();
+        let mut evaluator = Evaluator::new(outline, true);
+        evaluate_presentation(ast.statements.clone(), &mut evaluator, context)?;
+        context.presentation.set(snapshot).unwrap();
+        outline = evaluator.outline;
+    }
+    let mut evaluator = Evaluator::new(outline, false);
+    evaluate_presentation(ast.statements, &mut evaluator, context)?;
+    // dbg!(&context.presentation);
+    Ok(())
+}
 
+fn evaluate_presentation(
+    statements: Vec<BoundNode>,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> slides_rs_core::Result<()> {
     for module in &context.modules.modules {
         evaluator.set_variable(
             module.read().unwrap().name,
@@ -195,15 +343,23 @@ pub fn create_presentation_from_ast(
             },
         );
     }
+    evaluator.set_variable(
+        context
+            .string_interner
+            .create_or_get_variable("slide_count"),
+        Value {
+            value: value::Value::Integer(evaluator.outline.slides.len() as i64),
+            location: Location::zero(),
+        },
+    );
 
-    for statement in ast.statements {
-        evaluate_statement(statement, &mut evaluator, context)?;
+    for statement in statements {
+        evaluate_statement(statement, evaluator, context)?;
         if let Some(exception) = evaluator.exception.take() {
-            exception.print(&context.loaded_files);
+            evaluator.print_exception(exception, &context.loaded_files);
             break;
         }
     }
-    // dbg!(&context.presentation);
     Ok(())
 }
 
@@ -233,10 +389,34 @@ fn evaluate_statement(
         BoundNodeKind::ImportStatement(import_statement) => {
             evaluate_import_statement(import_statement, evaluator, context)
         }
+        BoundNodeKind::ThemeStatement(statements) => {
+            evaluate_theme_statement(statements, evaluator, context)
+        }
+        BoundNodeKind::TokensStatement(tokens) => {
+            evaluate_tokens_statement(tokens, evaluator, context)
+        }
+        // Sections only matter for the outline, which is known beforehand.
+        BoundNodeKind::SectionStatement(_) => Ok(()),
         err => unreachable!("No Top Level Statement: {err:?}"),
     }
 }
 
+fn evaluate_theme_statement(
+    statements: Vec<BoundNode>,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> slides_rs_core::Result<()> {
+    evaluator.is_theme = true;
+    for statement in statements {
+        evaluate_statement(statement, evaluator, context)?;
+        if evaluator.exception.is_some() {
+            break;
+        }
+    }
+    evaluator.is_theme = false;
+    Ok(())
+}
+
 fn evaluate_import_statement(
     import_statement: std::path::PathBuf,
     _evaluator: &mut Evaluator,
@@ -288,12 +468,18 @@ fn evaluate_element_statement(
     _context: &mut Context,
 ) -> slides_rs_core::Result<()> {
     let parameters = element_statement.parameters;
+    if !element_statement.properties.is_empty() {
+        evaluator
+            .element_properties
+            .insert(element_statement.type_, element_statement.properties);
+    }
     evaluator.set_variable(
         element_statement.name,
         Value {
             value: value::Value::UserFunction(value::UserFunctionValue {
                 has_implicit_slide_parameter: false,
                 parameters,
+                children_parameter: element_statement.children_parameter,
                 body: element_statement.body,
                 return_type: element_statement.type_,
             }),
@@ -313,6 +499,7 @@ fn evaluate_template_statement(
     let template = value::UserFunctionValue {
         has_implicit_slide_parameter: true,
         parameters,
+        children_parameter: template_statement.children_parameter,
         body: template_statement.body,
         return_type: TypeId::VOID,
     };
@@ -365,6 +552,9 @@ fn evaluate_slide_statement(
         .slide
         .take()
         .expect("Should still be set after evaluate_to_slide");
+    if let Some(title) = SlideInfo::title_of(&slide) {
+        evaluator.outline.slides[slide.index].title = title;
+    }
 
     context.presentation.write().unwrap().add_slide(slide);
     Ok(())
@@ -378,9 +568,50 @@ fn evaluate_global_statement(
     for statement in global_statement.body {
         slide::evaluate_statement(statement, evaluator, context)?;
         if let Some(exception) = evaluator.exception.take() {
-            exception.print(&context.loaded_files);
+            evaluator.print_exception(exception, &context.loaded_files);
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// Declares every token as a variable, whose value refers to the css custom
+/// property of the token instead of repeating the value itself.
+fn evaluate_tokens_statement(
+    tokens: Vec<super::binder::VariableDeclaration>,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> slides_rs_core::Result<()> {
+    for token in tokens {
+        let mut value = slide::evaluate_expression(*token.value, evaluator, context);
+        if let Some(exception) = evaluator.exception.take() {
+            evaluator.print_exception(exception, &context.loaded_files);
             return Ok(());
         }
+        let name = TokenName::new(context.string_interner.resolve_variable(token.variable));
+        let token_value = match value.value {
+            value::Value::Color(color) => TokenValue::Color(color),
+            value::Value::StyleUnit(style_unit) => TokenValue::StyleUnit(style_unit),
+            value::Value::Font(font) => TokenValue::Font(font),
+            _ => unreachable!("Tokens are checked by the binder"),
+        };
+        // A theme token with the same name wins, so the variable gets its
+        // value as well.
+        let token_value = {
+            let mut presentation = context.presentation.write().unwrap();
+            if evaluator.is_theme {
+                presentation.add_theme_token(name.clone(), token_value.clone());
+                token_value
+            } else {
+                presentation.add_token(name.clone(), token_value).clone()
+            }
+        };
+        value.value = match token_value {
+            TokenValue::Color(color) => value::Value::Color(color.with_token(name)),
+            TokenValue::StyleUnit(_) => value::Value::StyleUnit(StyleUnit::Token(name)),
+            TokenValue::Font(_) => value::Value::Font(Font::Token(name)),
+        };
+        evaluator.set_variable(token.variable, value);
     }
     Ok(())
 }
@@ -391,40 +622,108 @@ fn evaluate_styling_statement(
     evaluator: &mut Evaluator,
     context: &mut Context,
 ) -> slides_rs_core::Result<()> {
-    let name = context
+    let mut name = context
         .string_interner
         .resolve_variable(styling_statement.name)
         .to_owned();
-    let styling = match styling_statement.type_ {
+    let mut styling = match styling_statement.type_ {
         super::binder::StylingType::Label => LabelStyling::new().to_dynamic(name.clone()),
         super::binder::StylingType::Image => ImageStyling::new().to_dynamic(name.clone()),
         super::binder::StylingType::Slide => SlideStyling::new().to_dynamic(name.clone()),
+        super::binder::StylingType::Grid => {
+            GridStyling::new(Vec::new(), Vec::new()).to_dynamic(name.clone())
+        }
+        super::binder::StylingType::Flex => FlexStyling::new().to_dynamic(name.clone()),
+        super::binder::StylingType::CustomElement => {
+            let type_name = styling_statement
+                .custom_element
+                .expect("Binder sets the type name of the element");
+            // Custom elements use their type name as class.
+            if name == "default" {
+                name = type_name;
+            }
+            ElementStyling::new_base().to_dynamic(name.clone())
+        }
     };
+    // Inherited values are copied into the styling, so they do not depend on
+    // the order of the rules in the css.
+    let mut properties = Vec::new();
+    if let Some(base) = styling_statement.base {
+        let reference = evaluator
+            .get_variable(base)
+            .value
+            .clone()
+            .into_style_reference();
+        styling = context
+            .presentation
+            .read()
+            .unwrap()
+            .find_styling(&reference)
+            .expect("Binder checked the base styling")
+            .derive(name.clone());
+        if let Some(it) = evaluator.styling_properties.get(&reference.to_string()) {
+            properties.extend(it.iter().cloned());
+        }
+    }
+    // Properties of custom elements cannot be expressed in css, so they are
+    // applied by the evaluator, when the styling is used.
+    let (property_assignments, body): (Vec<_>, Vec<_>) =
+        styling_statement.body.into_iter().partition(|statement| {
+            statement
+                .kind
+                .try_as_assignment_statement_ref()
+                .and_then(|a| a.lhs.kind.try_as_variable_reference_ref())
+                .is_some_and(|v| styling_statement.properties.contains(&v.id))
+        });
+    for assignment in property_assignments {
+        let assignment = assignment.kind.into_assignment_statement();
+        let variable = assignment.lhs.kind.into_variable_reference();
+        let value = slide::evaluate_expression(*assignment.value, evaluator, context);
+        properties.push((variable.id, value));
+    }
+    if !properties.is_empty() {
+        evaluator
+            .styling_properties
+            .insert(name.clone(), properties);
+    }
     evaluator.styling = Some(styling);
     evaluator.push_scope();
-    let name = context.string_interner.create_or_get_variable("text");
+    // The parts of a label are styled through variables, which are written
+    // back into the styling afterwards.
+    let mut text_parts = Vec::new();
     if styling_statement.type_ == StylingType::Label {
-        evaluator.set_variable(
-            name,
-            Value {
-                value: value::Value::TextStyling(Arc::new(RwLock::new(TextStyling::default()))),
-                location,
-            },
-        );
+        let styling = evaluator.styling.as_mut().expect("styling");
+        for (part, text_styling) in styling.as_label_mut().text_parts_mut() {
+            let name = context.string_interner.create_or_get_variable(part);
+            text_parts.push((name, Arc::new(RwLock::new(text_styling.clone()))));
+        }
+        for (name, text_styling) in &text_parts {
+            evaluator.set_variable(
+                *name,
+                Value {
+                    value: value::Value::TextStyling(text_styling.clone()),
+                    location,
+                },
+            );
+        }
     }
-    style::evaluate_to_styling(styling_statement.body, evaluator, context);
+    style::evaluate_to_styling(body, evaluator, context);
     let mut styling = evaluator.styling.take().expect("styling");
-    if let Some(value) = evaluator.try_get_variable(name) {
-        styling
-            .as_label_mut()
-            .set_text_styling(value.value.clone().as_text_styling().get_cloned().unwrap());
+    if !text_parts.is_empty() {
+        let label = styling.as_label_mut();
+        for ((_, text_styling), (_, value)) in label.text_parts_mut().into_iter().zip(text_parts) {
+            *text_styling = value.read().unwrap().clone();
+        }
     }
     evaluator.drop_scope();
-    let reference = context
-        .presentation
-        .write()
-        .unwrap()
-        .add_dynamic_styling(styling);
+    let reference = {
+        let mut presentation = context.presentation.write().unwrap();
+        if evaluator.is_theme {
+            presentation.add_theme_styling(styling)
+        } else {
+            presentation.add_dynamic_styling(styling)
+        }
+    };
     evaluator.set_variable(
         styling_statement.name,
         Value {
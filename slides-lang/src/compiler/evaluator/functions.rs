@@ -1,13 +1,15 @@
-use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
 use slides_rs_core::{
-    BaseElementStyling, Color, Element, ElementId, Filter, Flex, Font, Grid, GridCellSize, Image,
-    ImageSource, Label, Position, PresentationEmitter, StyleUnit, WebRenderable,
-    WebRenderableContext,
+    Angle, Background, BackgroundImage, BackgroundSize, BaseElementStyling, Border, BorderSide,
+    BoxShadow, Color, ColorStop, Element, ElementId, Filter, Flex, Font, FontReferences, Grid,
+    GridCellSize, HorizontalAlignment, Image, ImageSource, Label, Position, PresentationEmitter,
+    StyleUnit, Transform, VerticalAlignment, WebRenderable, WebRenderableContext,
     animations::{Animation, AnimationValue, Trigger},
 };
 
 use super::Evaluator;
+use crate::Location;
 
 pub fn rgb(r: i64, g: i64, b: i64) -> Color {
     Color::rgb(r as _, g as _, b as _)
@@ -21,42 +23,183 @@ pub fn label(text: String) -> Label {
     Label::new(text)
 }
 
-pub fn grid(columns: String, rows: String) -> Grid {
-    fn parse_grid_cell_size(text: &str) -> GridCellSize {
-        let (index, number) = text
-            .char_indices()
-            .take_while(|(_, c)| c.is_digit(10))
-            .map(|(i, c)| {
-                (
-                    i + 1,
-                    c.to_digit(10).expect("Filtered digits before") as usize,
-                )
-            })
-            .reduce(|acc, val| {
-                let number = acc.1 * 10 + val.1;
-                (val.0, number)
-            })
-            .unwrap_or_else(|| (0, 1));
-
-        match &text[index..] {
-            "*" => GridCellSize::Fraction(number),
-            "min" => GridCellSize::Minimum,
-            unknown => todo!("Unknown unit {unknown}"),
+type GridCellSizes = Vec<GridCellSize>;
+
+pub fn grid(columns: GridCellSizes, rows: GridCellSizes) -> Grid {
+    Grid::new(columns, rows)
+}
+
+/// Parses comma separated cell sizes like `2*,min,*`. Strings are converted
+/// to cell sizes with it.
+pub(crate) fn parse_grid_cell_sizes(text: &str) -> Result<Vec<GridCellSize>, String> {
+    fn parse_grid_cell_size(text: &str) -> Result<GridCellSize, String> {
+        let text = text.trim();
+        let index = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        let number = match &text[..index] {
+            "" => Some(1),
+            digits => digits.parse().ok(),
+        };
+        match (number, &text[index..]) {
+            (Some(number), "*") => Ok(GridCellSize::Fraction(number)),
+            (_, "min") if index == 0 => Ok(GridCellSize::Minimum),
+            _ => Err(format!(
+                "Unknown grid cell size `{text}`, expected sizes like `2*`, `*` or `min`"
+            )),
         }
     }
-    let columns = columns.split(',').map(parse_grid_cell_size).collect();
-    let rows = rows.split(',').map(parse_grid_cell_size).collect();
-    Grid::new(columns, rows)
+    text.split(',').map(parse_grid_cell_size).collect()
+}
+
+type ColorStopArray = Vec<ColorStop>;
+
+pub fn stop(color: Color, position: StyleUnit) -> ColorStop {
+    ColorStop {
+        color,
+        position: Some(position),
+    }
+}
+
+/// Raises an exception if a gradient has fewer than two stops, which CSS
+/// does not accept.
+fn ensure_gradient_stops(evaluator: &mut Evaluator, stops: &[ColorStop]) {
+    if stops.len() >= 2 {
+        return;
+    }
+    // The caller moves the exception to the location of the call.
+    evaluator.exception = Some(super::Exception {
+        location: Location::zero(),
+        message: format!(
+            "Gradients need at least two stops, but only {} were given",
+            stops.len()
+        ),
+    });
+}
+
+pub fn linear_gradient(
+    _evaluator: &mut Evaluator,
+    angle: Angle,
+    stops: ColorStopArray,
+) -> Background {
+    ensure_gradient_stops(_evaluator, &stops);
+    Background::LinearGradient { angle, stops }
+}
+
+pub fn radial_gradient(_evaluator: &mut Evaluator, stops: ColorStopArray) -> Background {
+    ensure_gradient_stops(_evaluator, &stops);
+    Background::RadialGradient { stops }
+}
+
+pub fn conic_gradient(
+    _evaluator: &mut Evaluator,
+    from: Angle,
+    stops: ColorStopArray,
+) -> Background {
+    ensure_gradient_stops(_evaluator, &stops);
+    Background::ConicGradient { from, stops }
+}
+
+pub fn background_image(
+    path: PathBuf,
+    size: Option<BackgroundSize>,
+    position: Option<Position>,
+    repeat: Option<bool>,
+) -> Background {
+    let image = BackgroundImage::new(ImageSource::Path(path));
+    let position = position.unwrap_or(Position {
+        x: image.x.clone(),
+        y: image.y.clone(),
+    });
+    Background::Image(BackgroundImage {
+        size: size.unwrap_or(image.size.clone()),
+        x: position.x,
+        y: position.y,
+        repeat: repeat.unwrap_or(image.repeat),
+        ..image
+    })
+}
+
+pub fn background_size(size: StyleUnit) -> BackgroundSize {
+    BackgroundSize::Fixed(size)
+}
+
+pub fn solid_border(width: StyleUnit, color: Color) -> Border {
+    Border::all(BorderSide { width, color })
+}
+
+pub fn shadow(x: StyleUnit, y: StyleUnit, blur: StyleUnit, color: Color) -> BoxShadow {
+    BoxShadow { x, y, blur, color }
+}
+
+pub fn position(x: StyleUnit, y: StyleUnit) -> Position {
+    Position { x, y }
+}
+
+pub fn rotation(angle: Angle) -> Transform {
+    Transform::Rotate(angle)
+}
+
+pub fn scale(x: f64, y: f64) -> Transform {
+    Transform::Scale(x, y)
+}
+
+pub fn skew(x: Angle, y: Angle) -> Transform {
+    Transform::Skew(x, y)
+}
+
+pub fn translate(x: StyleUnit, y: StyleUnit) -> Transform {
+    Transform::Translate(x, y)
 }
 
 pub fn gfont(name: String) -> Font {
     Font::GoogleFont(name)
 }
 
+pub fn font(path: PathBuf) -> Font {
+    Font::file(path)
+}
+
 pub fn brightness(value: f64) -> Filter {
     Filter::Brightness(value)
 }
 
+pub fn blur(radius: StyleUnit) -> Filter {
+    Filter::Blur(radius)
+}
+
+pub fn grayscale(value: f64) -> Filter {
+    Filter::Grayscale(value)
+}
+
+pub fn contrast(value: f64) -> Filter {
+    Filter::Contrast(value)
+}
+
+pub fn saturate(value: f64) -> Filter {
+    Filter::Saturate(value)
+}
+
+pub fn sepia(value: f64) -> Filter {
+    Filter::Sepia(value)
+}
+
+pub fn hue_rotate(angle: Angle) -> Filter {
+    Filter::HueRotate(angle)
+}
+
+pub fn invert(value: f64) -> Filter {
+    Filter::Invert(value)
+}
+
+pub fn opacity(value: f64) -> Filter {
+    Filter::Opacity(value)
+}
+
+pub fn drop_shadow(x: StyleUnit, y: StyleUnit, blur: StyleUnit, color: Color) -> Filter {
+    Filter::DropShadow { x, y, blur, color }
+}
+
 pub fn string(value: i64) -> String {
     value.to_string()
 }
@@ -67,6 +210,117 @@ pub fn concat(value: StringArray) -> String {
     value.join("")
 }
 
+pub fn upper(text: String) -> String {
+    text.to_uppercase()
+}
+
+pub fn lower(text: String) -> String {
+    text.to_lowercase()
+}
+
+pub fn trim(text: String) -> String {
+    text.trim().to_owned()
+}
+
+pub fn split(text: String, separator: String) -> StringArray {
+    text.split(separator.as_str()).map(Into::into).collect()
+}
+
+pub fn join(values: StringArray, separator: String) -> String {
+    values.join(&separator)
+}
+
+pub fn replace(text: String, from: String, to: String) -> String {
+    text.replace(&from, &to)
+}
+
+pub fn contains(text: String, pattern: String) -> bool {
+    text.contains(&pattern)
+}
+
+pub fn starts_with(text: String, prefix: String) -> bool {
+    text.starts_with(&prefix)
+}
+
+/// The longest string `repeat`, `pad_left` and `fixed` create, in bytes.
+const MAX_STRING_LENGTH: usize = 1 << 20;
+
+/// Raises an exception if a string of `length` bytes would be longer than
+/// [`MAX_STRING_LENGTH`].
+fn ensure_string_length(evaluator: &mut Evaluator, length: Option<usize>) -> bool {
+    if length.is_some_and(|length| length <= MAX_STRING_LENGTH) {
+        return true;
+    }
+    // The caller moves the exception to the location of the call.
+    evaluator.exception = Some(super::Exception {
+        location: Location::zero(),
+        message: format!("Strings can be at most {MAX_STRING_LENGTH} bytes long"),
+    });
+    false
+}
+
+pub fn repeat(_evaluator: &mut Evaluator, text: String, count: i64) -> String {
+    let evaluator = _evaluator;
+    let count = count.max(0) as usize;
+    if !ensure_string_length(evaluator, text.len().checked_mul(count)) {
+        return String::new();
+    }
+    text.repeat(count)
+}
+
+/// Pads `text` on the left with the first char of `fill` (or a space) until it
+/// is `width` chars long.
+pub fn pad_left(_evaluator: &mut Evaluator, text: String, width: i64, fill: String) -> String {
+    let evaluator = _evaluator;
+    let fill = fill.chars().next().unwrap_or(' ');
+    let missing = (width.max(0) as usize).saturating_sub(text.chars().count());
+    if !ensure_string_length(evaluator, missing.checked_mul(fill.len_utf8())) {
+        return text;
+    }
+    let mut result: String = std::iter::repeat_n(fill, missing).collect();
+    result.push_str(&text);
+    result
+}
+
+/// Pads a formatted number with zeros after its sign until it is `width`
+/// chars long, so that `-5` becomes `-05` instead of `0-5`.
+pub fn pad_zeros(_evaluator: &mut Evaluator, number: String, width: i64) -> String {
+    let evaluator = _evaluator;
+    match number.strip_prefix('-') {
+        Some(digits) => {
+            let digits = pad_left(
+                evaluator,
+                digits.into(),
+                width.saturating_sub(1),
+                "0".into(),
+            );
+            format!("-{digits}")
+        }
+        None => pad_left(evaluator, number, width, "0".into()),
+    }
+}
+
+pub fn fixed(_evaluator: &mut Evaluator, value: f64, precision: i64) -> String {
+    let evaluator = _evaluator;
+    let precision = precision.max(0) as usize;
+    if !ensure_string_length(evaluator, Some(precision)) {
+        return value.to_string();
+    }
+    format!("{value:.*}", precision)
+}
+
+pub fn min(a: StyleUnit, b: StyleUnit) -> StyleUnit {
+    a.min(b)
+}
+
+pub fn max(a: StyleUnit, b: StyleUnit) -> StyleUnit {
+    a.max(b)
+}
+
+pub fn clamp(value: StyleUnit, min: StyleUnit, max: StyleUnit) -> StyleUnit {
+    value.clamp(min, max)
+}
+
 pub fn stackv(elements: Vec<Element>) -> Flex {
     let mut result = Flex::new(elements);
     result
@@ -117,26 +371,15 @@ impl WebRenderable for OwnedElement {
         }
     }
     #[inline]
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut HashSet<String>,
-    ) -> slides_rs_core::Result<()> {
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> slides_rs_core::Result<()> {
         match self {
-            OwnedElement::Image(inner) => {
-                WebRenderable::collect_google_font_references(inner, fonts)
-            }
-            OwnedElement::Label(inner) => {
-                WebRenderable::collect_google_font_references(inner, fonts)
-            }
+            OwnedElement::Image(inner) => WebRenderable::collect_font_references(inner, fonts),
+            OwnedElement::Label(inner) => WebRenderable::collect_font_references(inner, fonts),
             OwnedElement::CustomElement(inner) => {
-                WebRenderable::collect_google_font_references(inner, fonts)
-            }
-            OwnedElement::Grid(inner) => {
-                WebRenderable::collect_google_font_references(inner, fonts)
-            }
-            OwnedElement::Flex(inner) => {
-                WebRenderable::collect_google_font_references(inner, fonts)
+                WebRenderable::collect_font_references(inner, fonts)
             }
+            OwnedElement::Grid(inner) => WebRenderable::collect_font_references(inner, fonts),
+            OwnedElement::Flex(inner) => WebRenderable::collect_font_references(inner, fonts),
         }
     }
     #[inline]
@@ -276,14 +519,15 @@ pub fn leftTop(_evaluator: &mut Evaluator, element: Element) -> Position {
         .unwrap()
         .styling_mut()
         .base()
-        .padding;
+        .padding
+        .clone();
     let element = OwnedElement::from(element);
 
     let x = match element.element_styling().halign {
         slides_rs_core::HorizontalAlignment::Unset
         | slides_rs_core::HorizontalAlignment::Stretch
         | slides_rs_core::HorizontalAlignment::Left => {
-            element.element_styling().margin.left + slide_padding.left
+            element.element_styling().margin.left.clone() + slide_padding.left
         }
         slides_rs_core::HorizontalAlignment::Center => todo!(),
         slides_rs_core::HorizontalAlignment::Right => todo!(),
@@ -292,7 +536,7 @@ pub fn leftTop(_evaluator: &mut Evaluator, element: Element) -> Position {
         slides_rs_core::VerticalAlignment::Unset
         | slides_rs_core::VerticalAlignment::Stretch
         | slides_rs_core::VerticalAlignment::Top => {
-            element.element_styling().margin.top + slide_padding.top
+            element.element_styling().margin.top.clone() + slide_padding.top
         }
         slides_rs_core::VerticalAlignment::Center => todo!(),
         slides_rs_core::VerticalAlignment::Bottom => todo!(),
@@ -316,7 +560,8 @@ pub fn sizeOf(_evaluator: &mut Evaluator, element: Element) -> Position {
         .unwrap()
         .styling_mut()
         .base()
-        .padding;
+        .padding
+        .clone();
     let element = OwnedElement::from(element);
 
     let x = match element.element_styling().halign {
@@ -353,3 +598,42 @@ pub fn positionInside(_evaluator: &mut Evaluator, element: Element, x: f64, y: f
     let y = left_top.y + size.y * y;
     Position { x, y }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_padded(number: String, width: i64) -> String {
+        let mut evaluator = Evaluator::new(Default::default(), false);
+        pad_zeros(&mut evaluator, number, width)
+    }
+
+    #[test]
+    fn pad_zeros_keeps_the_sign_first() {
+        assert_eq!(format_padded("5".into(), 3), "005");
+        assert_eq!(format_padded("-5".into(), 3), "-05");
+        assert_eq!(format_padded("-123".into(), 3), "-123");
+    }
+
+    #[test]
+    fn pad_zeros_pads_fixed_floats() {
+        let mut evaluator = Evaluator::new(Default::default(), false);
+        let negative = fixed(&mut evaluator, -1.5, 2);
+        assert_eq!(format_padded(negative, 6), "-01.50");
+        let positive = fixed(&mut evaluator, 1.5, 2);
+        assert_eq!(format_padded(positive, 6), "001.50");
+    }
+
+    #[test]
+    fn gradients_need_two_stops() {
+        let mut evaluator = Evaluator::new(Default::default(), false);
+        let stop = ColorStop {
+            color: Color::WHITE,
+            position: None,
+        };
+        radial_gradient(&mut evaluator, vec![stop.clone(), stop.clone()]);
+        assert!(evaluator.exception.is_none());
+        radial_gradient(&mut evaluator, vec![stop]);
+        assert!(evaluator.exception.is_some());
+    }
+}
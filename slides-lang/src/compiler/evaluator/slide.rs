@@ -1,13 +1,20 @@
 use std::sync::{Arc, RwLock};
 use std::{collections::HashMap, path::PathBuf};
 
-use slides_rs_core::{Background, Color, CustomElement, Element, Label, Thickness, WebRenderable};
+use slides_rs_core::{
+    Angle, Background, Border, Color, CustomElement, Element, Filter, Label, StyleUnit, Thickness,
+    Transform, WebRenderable,
+};
 use string_interner::symbol::SymbolUsize;
 
-use crate::compiler::binder::{self, BoundNode, BoundNodeKind, typing::Type};
+use crate::compiler::binder::{
+    self, BoundNode, BoundNodeKind,
+    typing::{Type, TypeId},
+};
 use crate::{Context, Location, VariableId};
 
-use super::{Evaluator, Value, value};
+use super::functions::parse_grid_cell_sizes;
+use super::{ElementSource, Evaluator, Value, value};
 
 pub fn evaluate_to_slide(
     body: Vec<BoundNode>,
@@ -25,7 +32,7 @@ pub fn evaluate_to_slide(
     for statement in body {
         evaluate_statement(statement, evaluator, context)?;
         if let Some(exception) = evaluator.exception.take() {
-            exception.print(&context.loaded_files);
+            evaluator.print_exception(exception, &context.loaded_files);
             return Ok(());
         }
     }
@@ -88,7 +95,9 @@ pub(super) fn evaluate_statement(
         | BoundNodeKind::Literal(_)
         | BoundNodeKind::Dict(_)
         | BoundNodeKind::MemberAccess(_)
-        | BoundNodeKind::Conversion(_) => {
+        | BoundNodeKind::Conversion(_)
+        | BoundNodeKind::Match(_)
+        | BoundNodeKind::If(_) => {
             let _value = evaluate_expression(statement, evaluator, context);
             // if let Some(mut element) = value.value.try_convert_to_element() {
             //     if element.parent().is_none() {
@@ -106,10 +115,52 @@ pub(super) fn evaluate_statement(
         BoundNodeKind::VariableDeclaration(variable_declaration) => {
             evaluate_variable_declaration(variable_declaration, evaluator, context)
         }
+        BoundNodeKind::RemoveStatement(variable) => {
+            evaluator.current_scope().remove_variable(variable);
+            Ok(())
+        }
+        BoundNodeKind::MatchStatement(match_statement) => {
+            evaluate_match_statement(match_statement, evaluator, context)
+        }
+        BoundNodeKind::PropertyDeclaration(property_declaration) => {
+            // Properties set from outside of the element are already in scope.
+            let value = match evaluator
+                .current_scope()
+                .get_variable(property_declaration.variable)
+            {
+                Some(value) => value.clone(),
+                None => evaluate_expression(*property_declaration.value, evaluator, context),
+            };
+            evaluator.set_variable(property_declaration.variable, value);
+            Ok(())
+        }
         _ => unreachable!("Internal Compiler Error"),
     }
 }
 
+/// The statements of the matching arm are evaluated in the current scope, so
+/// elements declared in it are added to the enclosing slide or element.
+fn evaluate_match_statement(
+    match_statement: binder::MatchStatement,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> slides_rs_core::Result<()> {
+    let value = evaluate_expression(*match_statement.value, evaluator, context);
+    let variant = value.value.into_variant_name();
+    let arm = match_statement
+        .arms
+        .into_iter()
+        .find(|arm| arm.variant.as_ref().is_none_or(|v| v == &variant))
+        .expect("Binder checked that match is exhaustive");
+    for statement in arm.body {
+        evaluate_statement(statement, evaluator, context)?;
+        if evaluator.exception.is_some() {
+            break;
+        }
+    }
+    Ok(())
+}
+
 fn evaluate_variable_declaration(
     variable_declaration: crate::compiler::binder::VariableDeclaration,
     evaluator: &mut Evaluator,
@@ -207,6 +258,9 @@ pub(super) fn evaluate_expression(
         BoundNodeKind::Array(array) => {
             evaluate_array(array, expression.location, evaluator, context)
         }
+        BoundNodeKind::Children(body) => {
+            evaluate_children(body, expression.location, evaluator, context)
+        }
         BoundNodeKind::ArrayAccess(array_access) => {
             evaluate_array_access(array_access, expression.location, evaluator, context)
         }
@@ -216,6 +270,8 @@ pub(super) fn evaluate_expression(
         BoundNodeKind::Lambda(lambda) => {
             evaluate_lambda(lambda, expression.location, evaluator, context)
         }
+        BoundNodeKind::Match(match_) => evaluate_match(match_, evaluator, context),
+        BoundNodeKind::If(if_) => evaluate_if(if_, expression.location, evaluator, context),
         err => unreachable!("Only expressions can be evaluated! {err:#?}"),
     };
     if let Some(mut element) = value.value.clone().try_convert_to_element() {
@@ -233,18 +289,62 @@ fn evaluate_lambda(
     _evaluator: &mut Evaluator,
     _context: &mut Context,
 ) -> Value {
-    println!("LAMBDA");
     Value {
         location,
         value: value::Value::UserFunction(value::UserFunctionValue {
             has_implicit_slide_parameter: false,
             parameters: lambda.parameters,
+            children_parameter: None,
             return_type: lambda.body.type_,
             body: vec![*lambda.body],
         }),
     }
 }
 
+fn evaluate_if(
+    if_: binder::If,
+    location: Location,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> Value {
+    let condition = evaluate_expression(*if_.condition, evaluator, context);
+    let is_true = match if_.optional_binding {
+        Some(_) => !condition.value.is_none(),
+        None => *condition.value.as_bool(),
+    };
+    if is_true {
+        let Some(binding) = if_.optional_binding else {
+            return evaluate_expression(*if_.body, evaluator, context);
+        };
+        evaluator.push_scope().set_variable(binding, condition);
+        let value = evaluate_expression(*if_.body, evaluator, context);
+        evaluator.drop_scope();
+        value
+    } else if let Some(else_body) = if_.optional_else {
+        evaluate_expression(*else_body, evaluator, context)
+    } else {
+        Value {
+            value: value::Value::none(),
+            location,
+        }
+    }
+}
+
+fn evaluate_match(
+    match_: binder::Match,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> Value {
+    let value = evaluate_expression(*match_.value, evaluator, context);
+    let variant = value.value.into_variant_name();
+    let arm = match_
+        .arms
+        .into_iter()
+        .find(|arm| arm.variant.as_ref().is_none_or(|v| v == &variant))
+        .expect("Binder checked that match is exhaustive");
+    evaluate_expression(arm.body, evaluator, context)
+}
+
 fn evaluate_array_access(
     array_access: binder::ArrayAccess,
     location: Location,
@@ -265,11 +365,24 @@ fn evaluate_binary(
     context: &mut Context,
 ) -> Value {
     let lhs = evaluate_expression(*binary.lhs, evaluator, context);
-    let rhs = evaluate_expression(*binary.rhs, evaluator, context);
-    Value {
-        value: binary.operator.execute(lhs.value, rhs.value),
-        location,
+    if matches!(binary.operator, binder::BoundBinaryOperator::Coalesce) && !lhs.value.is_none() {
+        return Value {
+            value: lhs.value,
+            location,
+        };
     }
+    let rhs = evaluate_expression(*binary.rhs, evaluator, context);
+    let value = match binary.operator.execute(lhs.value, rhs.value) {
+        Some(value) => value,
+        None => {
+            evaluator.exception = Some(super::Exception {
+                location,
+                message: "Integer division by zero or overflow".into(),
+            });
+            value::Value::Integer(0)
+        }
+    };
+    Value { value, location }
 }
 
 fn evaluate_array(
@@ -290,6 +403,54 @@ fn evaluate_array(
     }
 }
 
+fn evaluate_children(
+    body: Vec<BoundNode>,
+    location: Location,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> Value {
+    evaluator.push_scope();
+    // Children are evaluated inside of an expression, so errors can only be
+    // raised as exceptions from here.
+    if let Err(error) = evaluate_children_body(body, evaluator, context) {
+        evaluator.exception = Some(super::Exception {
+            location,
+            message: error.to_string(),
+        });
+    }
+    let scope = evaluator.drop_scope();
+    let mut values = Vec::new();
+    for (name, value) in scope.variables() {
+        let Some(mut element) = value.value.clone().try_convert_to_element() else {
+            continue;
+        };
+        if element.parent().is_some() {
+            // Is already displayed as part of another element.
+            continue;
+        }
+        element.set_name(context.string_interner.resolve_variable(name).into());
+        values.push(value.value);
+    }
+    Value {
+        value: value::Value::Array(values),
+        location,
+    }
+}
+
+fn evaluate_children_body(
+    body: Vec<BoundNode>,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> slides_rs_core::Result<()> {
+    for statement in body {
+        evaluate_statement(statement, evaluator, context)?;
+        if evaluator.exception.is_some() {
+            break;
+        }
+    }
+    Ok(())
+}
+
 fn evaluate_dict(
     dict: Vec<(VariableId, BoundNode)>,
     location: Location,
@@ -322,7 +483,17 @@ fn evaluate_post_initialization(
     let dict = evaluate_expression(*post_initialization.dict, evaluator, context);
     let dict = dict.value.into_dict();
 
+    // The body of a user defined element is only evaluated again once for
+    // all of its properties set here.
+    let mut properties = Vec::new();
     for (member, value) in dict {
+        if let value::Value::CustomElement(element) = &base.value
+            && is_custom_property(element, &member, evaluator, context)
+        {
+            let property = context.string_interner.create_or_get_variable(&member);
+            properties.push((property, Value { value, location }));
+            continue;
+        }
         let member = context.string_interner.create_or_get(&member);
         let base_type = context.type_interner.resolve(base_type).clone();
         match base_type {
@@ -344,9 +515,50 @@ fn evaluate_post_initialization(
             }
         }
     }
+    if let value::Value::CustomElement(element) = &base.value
+        && !properties.is_empty()
+    {
+        set_properties(element, properties, location, evaluator, context);
+    }
     base
 }
 
+/// Whether `member` is a property declared by the user defined element
+/// instead of a styling member every element has.
+fn is_custom_property(
+    element: &Arc<RwLock<CustomElement>>,
+    member: &str,
+    evaluator: &Evaluator,
+    context: &mut Context,
+) -> bool {
+    let id = element.read().unwrap().id().raw();
+    let Some(source) = evaluator.element_sources.get(&id) else {
+        return false;
+    };
+    let member = context.string_interner.create_or_get_variable(member);
+    evaluator
+        .element_properties
+        .get(&source.function.return_type)
+        .is_some_and(|properties| properties.contains(&member))
+}
+
+/// Sets a value of the base styling of an element. User defined elements
+/// remember it, so that it is kept when their body is evaluated again.
+fn set_base_styling(
+    base: &value::Value,
+    evaluator: &mut Evaluator,
+    mut set: impl FnMut(&mut slides_rs_core::BaseElementStyling),
+) {
+    if let value::Value::CustomElement(element) = base {
+        let id = element.read().unwrap().id().raw();
+        if let Some(source) = evaluator.element_sources.get_mut(&id) {
+            set(&mut source.styling);
+        }
+    }
+    base.as_mut_base_element()
+        .apply_to_base_element_styling(set);
+}
+
 fn assign_to_slide_type(
     _base_type: Type,
     base: Value,
@@ -359,44 +571,80 @@ fn assign_to_slide_type(
     let member = context.string_interner.resolve(member);
     match member {
         "width" => {
-            base.as_mut_base_element()
-                .set_width(value.value.into_style_unit().into());
+            let width = value.value.into_style_unit();
+            set_base_styling(&base, evaluator, |base| base.set_width(width.clone()));
         }
         "height" => {
-            base.as_mut_base_element()
-                .set_height(value.value.into_style_unit().into());
+            let height = value.value.into_style_unit();
+            set_base_styling(&base, evaluator, |base| base.set_height(height.clone()));
         }
         "z_index" => {
-            base.as_mut_base_element()
-                .set_z_index(evaluator.ensure_unsigned(value));
+            let z_index = evaluator.ensure_unsigned(value);
+            set_base_styling(&base, evaluator, |base| base.set_z_index(z_index));
         }
         "valign" => {
-            base.as_mut_base_element()
-                .set_vertical_alignment(value.value.into_vertical_alignment());
+            let valign = value.value.into_vertical_alignment();
+            set_base_styling(&base, evaluator, |base| base.set_vertical_alignment(valign));
         }
         "halign" => {
-            base.as_mut_base_element()
-                .set_horizontal_alignment(value.value.into_horizontal_alignment());
+            let halign = value.value.into_horizontal_alignment();
+            set_base_styling(&base, evaluator, |base| {
+                base.set_horizontal_alignment(halign)
+            });
         }
         "margin" => {
-            base.as_mut_base_element()
-                .set_margin(value.value.into_thickness());
+            let margin = value.value.into_thickness();
+            set_base_styling(&base, evaluator, |base| base.set_margin(margin.clone()));
         }
         "padding" => {
-            base.as_mut_base_element()
-                .set_padding(value.value.into_thickness());
+            let padding = value.value.into_thickness();
+            set_base_styling(&base, evaluator, |base| base.set_padding(padding.clone()));
         }
         "background" => {
-            base.as_mut_base_element()
-                .set_background(value.value.into_background());
+            let background = value.value.into_background();
+            set_base_styling(&base, evaluator, |base| {
+                base.set_background(background.clone())
+            });
         }
         "filter" => {
-            base.as_mut_base_element()
-                .set_filter(value.value.into_filter());
+            let filter = value.value.into_filter();
+            set_base_styling(&base, evaluator, |base| base.set_filter(filter.clone()));
         }
         "rotate" => {
-            base.as_mut_base_element()
-                .set_rotation(value.value.into_integer());
+            let rotate = value.value.into_angle();
+            set_base_styling(&base, evaluator, |base| base.set_rotation(rotate));
+        }
+        "border" => {
+            let border = value.value.into_border();
+            set_base_styling(&base, evaluator, |base| base.set_border(border.clone()));
+        }
+        "border_radius" => {
+            let border_radius = value.value.into_style_unit();
+            set_base_styling(&base, evaluator, |base| {
+                base.set_border_radius(border_radius.clone())
+            });
+        }
+        "box_shadow" => {
+            let box_shadow = value.value.into_box_shadow();
+            set_base_styling(&base, evaluator, |base| {
+                base.set_box_shadow(box_shadow.clone())
+            });
+        }
+        "opacity" => {
+            let opacity = value.value.into_float();
+            set_base_styling(&base, evaluator, |base| base.set_opacity(opacity));
+        }
+        "transform" => {
+            let transform = value.value.into_transform();
+            set_base_styling(&base, evaluator, |base| {
+                base.set_transform(transform.clone())
+            });
+        }
+        "transform_origin" => {
+            let transform_origin = value.value.into_position();
+            set_base_styling(&base, evaluator, |base| {
+                base.set_transform_origin(transform_origin.clone())
+            });
         }
         "animations" => {
             let animations = value
@@ -408,9 +656,35 @@ fn assign_to_slide_type(
             base.as_mut_base_element().set_animations(animations);
         }
         "styles" => {
+            let mut properties = Vec::new();
+            let mut references = Vec::new();
             for value in value.value.into_array() {
-                base.as_mut_base_element()
-                    .add_styling_reference(value.into_style_reference())
+                let reference = value.into_style_reference();
+                if let Some(it) = evaluator.styling_properties.get(&reference.to_string()) {
+                    properties.extend(it.iter().cloned());
+                }
+                references.push(reference);
+            }
+            // Later stylings take precedence, which css cannot express for
+            // several classes, so they are merged into a single styling.
+            let reference = match references.len() {
+                0 => None,
+                1 => references.pop(),
+                _ => Some(
+                    context
+                        .presentation
+                        .write()
+                        .unwrap()
+                        .combine_stylings(&references),
+                ),
+            };
+            if let Some(reference) = reference {
+                base.as_mut_base_element().add_styling_reference(reference)
+            }
+            if let value::Value::CustomElement(element) = &base
+                && !properties.is_empty()
+            {
+                set_properties(element, properties, value.location, evaluator, context);
             }
         }
         "object_fit" => {
@@ -439,7 +713,7 @@ fn assign_to_slide_type(
                 .write()
                 .unwrap()
                 .element_styling_mut()
-                .set_font_size(evaluator.ensure_unsigned_float(value));
+                .set_font_size(value.value.into_style_unit());
         }
         "font_weight" => {
             base.as_label()
@@ -448,6 +722,69 @@ fn assign_to_slide_type(
                 .element_styling_mut()
                 .set_font_weight(evaluator.ensure_unsigned(value));
         }
+        "font_style" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_font_style(value.value.into_font_style());
+        }
+        "line_height" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_line_height(evaluator.ensure_unsigned_float(value));
+        }
+        "letter_spacing" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_letter_spacing(value.value.into_style_unit());
+        }
+        "text_decoration" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_text_decoration(value.value.into_text_decoration());
+        }
+        "text_transform" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_text_transform(value.value.into_text_transform());
+        }
+        "text_shadow" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_text_shadow(value.value.into_box_shadow());
+        }
+        "white_space" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_white_space(value.value.into_white_space());
+        }
+        "hyphenate" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_hyphenate(value.value.into_bool());
+        }
+        "column_count" => {
+            base.as_label()
+                .write()
+                .unwrap()
+                .element_styling_mut()
+                .set_column_count(evaluator.ensure_unsigned(value));
+        }
         "column_span" => {
             base.as_grid_entry().write().unwrap().column_span = evaluator.ensure_unsigned(value);
         }
@@ -459,10 +796,79 @@ fn assign_to_slide_type(
                     .add_element(element.convert_to_element());
             }
         }
+        "columns" => {
+            base.as_grid()
+                .write()
+                .unwrap()
+                .styling_mut()
+                .set_columns(value.value.into_grid_cell_sizes());
+        }
+        "rows" => {
+            base.as_grid()
+                .write()
+                .unwrap()
+                .styling_mut()
+                .set_rows(value.value.into_grid_cell_sizes());
+        }
+        "direction" => {
+            base.as_flex()
+                .write()
+                .unwrap()
+                .styling_mut()
+                .set_direction(value.value.into_flex_direction());
+        }
+        property if matches!(base, value::Value::CustomElement(_)) => {
+            let property = context.string_interner.create_or_get_variable(property);
+            let location = value.location;
+            set_properties(
+                base.as_custom_element(),
+                vec![(property, value)],
+                location,
+                evaluator,
+                context,
+            );
+        }
         missing => unreachable!("Missing Member {missing}"),
     }
 }
 
+/// Evaluates the body of a user defined element again with the new values of
+/// its properties and replaces the children of the element and the styling
+/// set by its body with the result.
+fn set_properties(
+    element: &Arc<RwLock<CustomElement>>,
+    properties: Vec<(VariableId, Value)>,
+    location: Location,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) {
+    let id = element.read().unwrap().id().raw();
+    let source = evaluator
+        .element_sources
+        .get_mut(&id)
+        .expect("Only properties of custom elements can be set");
+    for (property, value) in properties {
+        source.properties.retain(|(p, _)| *p != property);
+        source.properties.push((property, value));
+    }
+    let source = source.clone();
+    let result = run_user_function(
+        source.function,
+        source.arguments,
+        source.properties,
+        location,
+        evaluator,
+        context,
+    );
+    let result = result.value.as_custom_element().read().unwrap();
+    let mut element = element.write().unwrap();
+    element.set_elements(result.children().to_vec());
+    let base = element.element_styling_mut().base_mut();
+    base.merge(result.element_styling());
+    // Styling set on the element itself is kept.
+    base.merge(&source.styling);
+}
+
 fn evaluate_member_access(
     member_access: binder::MemberAccess,
     location: Location,
@@ -484,6 +890,20 @@ fn evaluate_member_access(
                 value::Value::VerticalAlignment(variant.parse().expect("Valid variant"))
             }
             &Type::TextAlign => value::Value::TextAlign(variant.parse().expect("Valid variant")),
+            &Type::FontStyle => value::Value::FontStyle(variant.parse().expect("Valid variant")),
+            &Type::TextDecoration => {
+                value::Value::TextDecoration(variant.parse().expect("Valid variant"))
+            }
+            &Type::TextTransform => {
+                value::Value::TextTransform(variant.parse().expect("Valid variant"))
+            }
+            &Type::WhiteSpace => value::Value::WhiteSpace(variant.parse().expect("Valid variant")),
+            &Type::FlexDirection => {
+                value::Value::FlexDirection(variant.parse().expect("Valid variant"))
+            }
+            &Type::BackgroundSize => {
+                value::Value::BackgroundSize(variant.parse().expect("Valid variant"))
+            }
             &Type::Enum(_) => value::Value::String(variant.into()),
             _ => unreachable!("Type {enum_type:?} is not an enum!"),
         };
@@ -514,6 +934,12 @@ fn evaluate_member_access(
                     _ => unreachable!("Member {member} not found!"),
                 }
             }
+            value::Value::Dict(mut entries) => {
+                let member = context.string_interner.resolve(member_access.member);
+                entries
+                    .remove(member)
+                    .expect("Binder checked that the field exists")
+            }
             _ => todo!(),
         };
         Value { value, location }
@@ -641,6 +1067,18 @@ fn execute_member_function(
             _ => todo!(),
         },
         value::Value::Module(module) => {
+            if evaluator.is_first_pass {
+                let return_type = module
+                    .read()
+                    .unwrap()
+                    .try_get_function_by_name(&name)
+                    .map(|f| f.type_.return_type)
+                    .unwrap_or(TypeId::VOID);
+                return Value {
+                    value: module_placeholder(context.type_interner.resolve(return_type)),
+                    location,
+                };
+            }
             let value = match module
                 .write()
                 .unwrap()
@@ -662,6 +1100,22 @@ fn execute_member_function(
     }
 }
 
+/// Stands in for the result of a module function during the first pass.
+/// Modules keep their own state and change the presentation, so they are only
+/// called in the second pass. Slide titles computed by a module are therefore
+/// not known to `slide` and `toc`.
+fn module_placeholder(return_type: &Type) -> value::Value {
+    match return_type {
+        Type::Integer => value::Value::Integer(0),
+        Type::Float => value::Value::Float(0.0),
+        Type::String => value::Value::String(String::new()),
+        Type::StyleUnit => value::Value::StyleUnit(StyleUnit::default()),
+        Type::Position => value::Value::Position(Default::default()),
+        // Modules can not return any other values.
+        _ => value::Value::Void(()),
+    }
+}
+
 fn execute_named_function(
     name: String,
     arguments: Vec<Value>,
@@ -669,14 +1123,40 @@ fn execute_named_function(
     evaluator: &mut Evaluator,
     context: &mut Context,
 ) -> Value {
+    // Builtins are only shadowed by functions, not by other variables like a
+    // slide called `toc`.
+    let is_shadowed = matches!(
+        evaluator
+            .try_get_variable(context.string_interner.create_or_get_variable(&name))
+            .map(|v| &v.value),
+        Some(value::Value::UserFunction(_))
+    );
     match binder::globals::FUNCTIONS
         .iter()
         .find(|f| f.name == name.as_str())
     {
-        Some(it) => Value {
-            value: (it.call)(evaluator, arguments),
-            location,
-        },
+        Some(it) => {
+            let value = (it.call)(evaluator, arguments);
+            // Builtins do not know where they are called from.
+            if let Some(exception) = &mut evaluator.exception
+                && exception.location == Location::zero()
+            {
+                exception.location = location;
+            }
+            Value { value, location }
+        }
+        None if binder::globals::ARRAY_FUNCTIONS.contains(&name.as_str()) && !is_shadowed => {
+            execute_array_function(&name, arguments, location, evaluator, context)
+        }
+        None if binder::globals::PRESENTATION_FUNCTIONS.contains(&name.as_str())
+            && !is_shadowed =>
+        {
+            match name.as_str() {
+                "slide" => execute_slide_lookup(arguments, location, evaluator),
+                "toc" => execute_toc(location, evaluator),
+                _ => unreachable!("Unknown presentation function {name}"),
+            }
+        }
         None => {
             let value = evaluator
                 .get_variable_mut(context.string_interner.create_or_get_variable(&name))
@@ -692,6 +1172,188 @@ fn execute_named_function(
     }
 }
 
+fn execute_slide_lookup(
+    arguments: Vec<Value>,
+    location: Location,
+    evaluator: &mut Evaluator,
+) -> Value {
+    let name = arguments.into_iter().next().unwrap().value.into_string();
+    let Some(index) = evaluator.outline.slides.iter().position(|s| s.name == name) else {
+        evaluator.exception = Some(super::Exception {
+            location,
+            message: format!("There is no slide named {name}"),
+        });
+        return Value {
+            value: value::Value::Void(()),
+            location,
+        };
+    };
+    let info = &evaluator.outline.slides[index];
+    Value {
+        value: value::Value::Dict(HashMap::from([
+            ("index".into(), value::Value::Integer(index as i64)),
+            ("name".into(), value::Value::String(info.name.clone())),
+            ("title".into(), value::Value::String(info.title.clone())),
+        ])),
+        location,
+    }
+}
+
+/// Lists all numbered slides grouped by their sections, each linking to its
+/// slide.
+fn execute_toc(location: Location, evaluator: &mut Evaluator) -> Value {
+    let outline = &evaluator.outline;
+    let mut markdown = String::new();
+    let mut current_section = None;
+    for info in outline.slides.iter().filter(|s| s.page_number.is_some()) {
+        if info.section != current_section {
+            current_section = info.section;
+            if let Some(section) = info.section {
+                markdown.push_str(&format!(
+                    "1. [{}](#{})\n",
+                    escape_markdown(&outline.sections[section]),
+                    info.name
+                ));
+            }
+        }
+        let indent = if info.section.is_some() { "    " } else { "" };
+        markdown.push_str(&format!(
+            "{indent}1. [{}](#{})\n",
+            escape_markdown(&info.title),
+            info.name
+        ));
+    }
+    Value {
+        value: Label::new(markdown).into(),
+        location,
+    }
+}
+
+/// Escapes every char, which markdown could read as syntax. Labels do not
+/// support backslash escapes, so character references are used instead.
+fn escape_markdown(text: &str) -> String {
+    const MARKDOWN_SYNTAX_CHARS: &[char] = &[
+        '\\', '`', '*', '_', '~', '$', '[', ']', '(', ')', '<', '>', '&', '#', '!',
+    ];
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if MARKDOWN_SYNTAX_CHARS.contains(&c) {
+            result.push_str(&format!("&#{};", c as u32));
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// The most elements `range` creates.
+const MAX_ARRAY_LENGTH: i64 = 1 << 20;
+
+fn execute_array_function(
+    name: &str,
+    arguments: Vec<Value>,
+    location: Location,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> Value {
+    let mut arguments = arguments.into_iter().map(|a| a.value);
+    let mut call = |callback: &value::Value, value: value::Value| {
+        evaluate_user_function(
+            callback.as_user_function().clone(),
+            vec![Value { value, location }],
+            location,
+            evaluator,
+            context,
+        )
+        .value
+    };
+    let value = match name {
+        "range" => {
+            let first = arguments.next().unwrap().into_integer();
+            let (start, end) = match arguments.next() {
+                Some(end) => (first, end.into_integer()),
+                None => (0, first),
+            };
+            let length = end.checked_sub(start).map(|length| length.max(0));
+            if length.is_none_or(|length| length > MAX_ARRAY_LENGTH) {
+                evaluator.exception = Some(super::Exception {
+                    location,
+                    message: format!("Arrays can have at most {MAX_ARRAY_LENGTH} elements"),
+                });
+                return Value {
+                    value: value::Value::Array(Vec::new()),
+                    location,
+                };
+            }
+            value::Value::Array((start..end).map(value::Value::Integer).collect())
+        }
+        "len" => match arguments.next().unwrap() {
+            value::Value::String(text) => value::Value::Integer(text.chars().count() as i64),
+            value::Value::Array(values) => value::Value::Integer(values.len() as i64),
+            _ => unreachable!("Binder checked the argument type"),
+        },
+        _ => {
+            let array = arguments.next().unwrap().into_array();
+            match name {
+                "reverse" => value::Value::Array(array.into_iter().rev().collect()),
+                "first" => array.into_iter().next().unwrap_or_else(value::Value::none),
+                "last" => array.into_iter().last().unwrap_or_else(value::Value::none),
+                "map" => {
+                    let callback = arguments.next().unwrap();
+                    value::Value::Array(array.into_iter().map(|v| call(&callback, v)).collect())
+                }
+                "filter" => {
+                    let callback = arguments.next().unwrap();
+                    value::Value::Array(
+                        array
+                            .into_iter()
+                            .filter(|v| call(&callback, v.clone()).into_bool())
+                            .collect(),
+                    )
+                }
+                "flat_map" => {
+                    let callback = arguments.next().unwrap();
+                    value::Value::Array(
+                        array
+                            .into_iter()
+                            .flat_map(|v| call(&callback, v).into_array())
+                            .collect(),
+                    )
+                }
+                "enumerate" => value::Value::Array(
+                    array
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, value)| {
+                            value::Value::Dict(HashMap::from([
+                                ("index".into(), value::Value::Integer(index as i64)),
+                                ("value".into(), value),
+                            ]))
+                        })
+                        .collect(),
+                ),
+                "zip" => {
+                    let other = arguments.next().unwrap().into_array();
+                    value::Value::Array(
+                        array
+                            .into_iter()
+                            .zip(other)
+                            .map(|(first, second)| {
+                                value::Value::Dict(HashMap::from([
+                                    ("first".into(), first),
+                                    ("second".into(), second),
+                                ]))
+                            })
+                            .collect(),
+                    )
+                }
+                _ => unreachable!("Unknown array function {name}"),
+            }
+        }
+    };
+    Value { value, location }
+}
+
 fn evaluate_user_function(
     user_function: value::UserFunctionValue,
     arguments: Vec<Value>,
@@ -699,23 +1361,92 @@ fn evaluate_user_function(
     evaluator: &mut Evaluator,
     context: &mut Context,
 ) -> Value {
-    let implicit_fields: &[(crate::VariableId, Value)] =
-        if user_function.has_implicit_slide_parameter {
-            &[(
-                context
-                    .string_interner
-                    .create_or_get_variable("slide_index"),
+    if !evaluator
+        .element_properties
+        .contains_key(&user_function.return_type)
+    {
+        return run_user_function(
+            user_function,
+            arguments,
+            Vec::new(),
+            location,
+            evaluator,
+            context,
+        );
+    }
+    let type_name = context
+        .type_interner
+        .resolve(user_function.return_type)
+        .try_as_custom_element_ref()
+        .map(|(type_name, _)| type_name.clone())
+        .unwrap_or_default();
+    // The default styling of the element may set some properties.
+    let properties = evaluator
+        .styling_properties
+        .get(&type_name)
+        .cloned()
+        .unwrap_or_default();
+    let source = ElementSource {
+        function: user_function.clone(),
+        arguments: arguments.clone(),
+        properties: properties.clone(),
+        styling: Default::default(),
+    };
+    let result = run_user_function(
+        user_function,
+        arguments,
+        properties,
+        location,
+        evaluator,
+        context,
+    );
+    if let value::Value::CustomElement(element) = &result.value {
+        let id = element.read().unwrap().id().raw();
+        evaluator.element_sources.insert(id, source);
+    }
+    result
+}
+
+fn run_user_function(
+    user_function: value::UserFunctionValue,
+    arguments: Vec<Value>,
+    properties: Vec<(VariableId, Value)>,
+    location: Location,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) -> Value {
+    let mut implicit_fields = Vec::new();
+    if user_function.has_implicit_slide_parameter {
+        let index = evaluator.slide.as_ref().unwrap().index;
+        let info = &evaluator.outline.slides[index];
+        let optional_integer = |number: Option<usize>| {
+            number.map_or_else(value::Value::none, |number| {
+                value::Value::Integer(number as i64)
+            })
+        };
+        for (name, value) in [
+            ("slide_index", value::Value::Integer(index as i64)),
+            (
+                "section_number",
+                optional_integer(info.section.map(|s| s + 1)),
+            ),
+            ("page_number", optional_integer(info.page_number)),
+        ] {
+            implicit_fields.push((
+                context.string_interner.create_or_get_variable(name),
                 Value {
-                    value: value::Value::Integer(evaluator.slide.as_ref().unwrap().index as i64),
+                    value,
                     location: Location::zero(),
                 },
-            )]
-        } else {
-            &[]
-        };
+            ));
+        }
+    }
+    // Only the elements passed as children are added to the element, other
+    // arrays are just values used by its body.
+    let children_parameter = user_function.children_parameter;
     let scope = evaluator.push_scope();
     for (id, value) in implicit_fields {
-        scope.set_variable(*id, value.clone());
+        scope.set_variable(id, value);
     }
     for (parameter, value) in user_function.parameters.into_iter().zip(
         arguments
@@ -728,6 +1459,9 @@ fn evaluate_user_function(
             .unwrap();
         scope.set_variable(parameter.id, value);
     }
+    for (id, value) in properties {
+        scope.set_variable(id, value);
+    }
     for statement in user_function.body {
         evaluate_statement(statement, evaluator, context).unwrap();
     }
@@ -764,6 +1498,24 @@ fn evaluate_user_function(
             }
             _ => {}
         }
+        if children_parameter == Some(name) {
+            // Children passed to the element, which are not placed anywhere
+            // by its body. Children of a trailing body are already named.
+            let name = context.string_interner.resolve_variable(name);
+            let children = value
+                .value
+                .into_array()
+                .into_iter()
+                .filter_map(|v| v.try_convert_to_element())
+                .filter(|e| e.parent().is_none());
+            for (index, mut child) in children.enumerate() {
+                if child.name().is_empty() {
+                    child.set_name(format!("{name}_{index}"));
+                }
+                elements.push(child);
+            }
+            continue;
+        }
         let Some(mut element) = value.value.try_convert_to_element() else {
             continue;
         };
@@ -800,6 +1552,10 @@ fn evaluate_conversion(
             value::Value::Color(color) => value::Value::Background(Background::Color(color)),
             _ => unreachable!("Impossible conversion!"),
         },
+        Type::ColorStop => match base.value {
+            value::Value::Color(color) => value::Value::ColorStop(color.into()),
+            _ => unreachable!("Impossible conversion!"),
+        },
         Type::Color => match base.value {
             value::Value::String(text) => value::Value::Color(Color::from_css(&text)),
             _ => unreachable!("Impossible conversion!"),
@@ -808,6 +1564,48 @@ fn evaluate_conversion(
             value::Value::Integer(number) => value::Value::Float(number as _),
             _ => unreachable!("Impossible conversion!"),
         },
+        Type::GridCellSizes => match parse_grid_cell_sizes(base.value.as_string()) {
+            Ok(sizes) => value::Value::GridCellSizes(sizes),
+            Err(message) => {
+                evaluator.exception = Some(super::Exception { location, message });
+                value::Value::GridCellSizes(Vec::new())
+            }
+        },
+        // Plain numbers are degrees.
+        Type::Angle => match base.value {
+            value::Value::Integer(number) => value::Value::Angle(Angle::Degree(number as _)),
+            value::Value::Float(number) => value::Value::Angle(Angle::Degree(number)),
+            _ => unreachable!("Impossible conversion!"),
+        },
+        // Plain numbers are multiples of the default font size, which is
+        // 0.04sh.
+        Type::FontSize => match base.value {
+            value::Value::Integer(number) => {
+                value::Value::StyleUnit(StyleUnit::SlideHeightRatio(number as f64 * 0.04))
+            }
+            value::Value::Float(number) => {
+                value::Value::StyleUnit(StyleUnit::SlideHeightRatio(number * 0.04))
+            }
+            value::Value::StyleUnit(unit) => value::Value::StyleUnit(unit),
+            _ => unreachable!("Impossible conversion!"),
+        },
+        // Font sizes are style units already.
+        Type::StyleUnit => match base.value {
+            value::Value::StyleUnit(unit) => value::Value::StyleUnit(unit),
+            _ => unreachable!("Impossible conversion!"),
+        },
+        Type::Filter => match base.value {
+            value::Value::Array(filters) => {
+                value::Value::Filter(Filter::chain(filters.into_iter().map(|f| f.into_filter())))
+            }
+            _ => unreachable!("Impossible conversion!"),
+        },
+        Type::Transform => match base.value {
+            value::Value::Array(transforms) => value::Value::Transform(Transform::chain(
+                transforms.into_iter().map(|t| t.into_transform()),
+            )),
+            _ => unreachable!("Impossible conversion!"),
+        },
         Type::Path => match base.value {
             value::Value::String(text) => value::Value::Path(PathBuf::from(text)),
             _ => unreachable!("Impossible converion!"),
@@ -855,9 +1653,27 @@ fn evaluate_conversion(
             }
             _ => unreachable!("Impossible conversion"),
         },
+        Type::Border => match base.value {
+            value::Value::Dict(entries) => {
+                let mut border = Border::default();
+                for (name, value) in entries {
+                    let value = value.into_border();
+                    match name.as_str() {
+                        "top" => border.top = value.top,
+                        "left" => border.left = value.left,
+                        "bottom" => border.bottom = value.bottom,
+                        "right" => border.right = value.right,
+                        _ => unreachable!("Impossible conversion"),
+                    }
+                }
+                border.into()
+            }
+            _ => unreachable!("Impossible conversion"),
+        },
         Type::String => match base.value {
             value::Value::Float(x) => value::Value::String(x.to_string()),
             value::Value::Integer(x) => value::Value::String(x.to_string()),
+            value::Value::Bool(x) => value::Value::String(x.to_string()),
             value::Value::String(x) => value::Value::String(x),
             value::Value::Path(x) => value::Value::String(x.to_string_lossy().into_owned()),
             value::Value::None(x) => value::Value::String(String::new()),
@@ -18,6 +18,18 @@ fn evaluate_statement(statement: BoundNode, evaluator: &mut Evaluator, context:
         BoundNodeKind::VariableDeclaration(_variable_declaration) => {
             todo!()
         }
+        BoundNodeKind::MatchStatement(match_statement) => {
+            let value = evaluate_expression(*match_statement.value, evaluator, context);
+            let variant = value.value.into_string();
+            let arm = match_statement
+                .arms
+                .into_iter()
+                .find(|arm| arm.variant.as_ref().is_none_or(|v| v == &variant))
+                .expect("Binder checked that match is exhaustive");
+            for statement in arm.body {
+                evaluate_statement(statement, evaluator, context);
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -27,12 +39,16 @@ fn evaluate_assignment_statement(
     evaluator: &mut Evaluator,
     context: &mut Context,
 ) {
-    let value: Value =
-        super::slide::evaluate_expression(*assignment_statement.value, evaluator, context).value;
+    let value = super::slide::evaluate_expression(*assignment_statement.value, evaluator, context);
     assign_to(*assignment_statement.lhs, value, evaluator, context);
 }
 
-fn assign_to(lhs: BoundNode, value: Value, evaluator: &mut Evaluator, context: &mut Context) {
+fn assign_to(
+    lhs: BoundNode,
+    value: super::Value,
+    evaluator: &mut Evaluator,
+    context: &mut Context,
+) {
     match lhs.kind {
         BoundNodeKind::VariableReference(variable) => {
             assign_to_field(variable.id, value, evaluator, context);
@@ -40,12 +56,56 @@ fn assign_to(lhs: BoundNode, value: Value, evaluator: &mut Evaluator, context: &
         BoundNodeKind::MemberAccess(member_access) => {
             let base = evaluate_expression(*member_access.base, evaluator, context).value;
             let member = context.string_interner.resolve(member_access.member);
+            let text_styling = base.as_text_styling();
             match member {
-                "text_color" => base
-                    .as_text_styling()
+                "text_color" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_text_color(value.value.into_color()),
+                "text_align" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_text_align(value.value.into_text_align()),
+                "font" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_font(value.value.into_font()),
+                "font_size" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_font_size(value.value.into_style_unit()),
+                "font_weight" => {
+                    let font_weight = evaluator.ensure_unsigned(value);
+                    text_styling.write().unwrap().set_font_weight(font_weight)
+                }
+                "letter_spacing" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_letter_spacing(value.value.into_style_unit()),
+                "line_height" => {
+                    let line_height = evaluator.ensure_unsigned_float(value);
+                    text_styling.write().unwrap().set_line_height(line_height)
+                }
+                "font_style" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_font_style(value.value.into_font_style()),
+                "text_decoration" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_text_decoration(value.value.into_text_decoration()),
+                "text_transform" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_text_transform(value.value.into_text_transform()),
+                "text_shadow" => text_styling
+                    .write()
+                    .unwrap()
+                    .set_text_shadow(value.value.into_box_shadow()),
+                "margin" => text_styling
                     .write()
                     .unwrap()
-                    .set_text_color(value.into_color()),
+                    .set_margin(value.value.into_thickness()),
                 missing => unreachable!("Missing member {missing}"),
             }
         }
@@ -56,11 +116,14 @@ fn assign_to(lhs: BoundNode, value: Value, evaluator: &mut Evaluator, context: &
 
 fn assign_to_field(
     name: VariableId,
-    value: Value,
+    value: super::Value,
     evaluator: &mut Evaluator,
     context: &mut Context,
 ) {
-    let styling = evaluator.styling.as_mut().expect("");
+    let location = value.location;
+    let value: Value = value.value;
+    // Taken out of the evaluator, so that invalid values can be reported.
+    let mut styling = evaluator.styling.take().expect("");
     match context.string_interner.resolve_variable(name) {
         "halign" => {
             styling
@@ -81,7 +144,50 @@ fn assign_to_field(
                 .set_text_align(value.into_text_align());
         }
         "font_size" => {
-            styling.as_label_mut().set_font_size(value.into_float());
+            styling
+                .as_label_mut()
+                .set_font_size(value.into_style_unit());
+        }
+        "font_style" => {
+            styling
+                .as_label_mut()
+                .set_font_style(value.into_font_style());
+        }
+        "line_height" => {
+            let line_height = evaluator.ensure_unsigned_float(super::Value { value, location });
+            styling.as_label_mut().set_line_height(line_height);
+        }
+        "letter_spacing" => {
+            styling
+                .as_label_mut()
+                .set_letter_spacing(value.into_style_unit());
+        }
+        "text_decoration" => {
+            styling
+                .as_label_mut()
+                .set_text_decoration(value.into_text_decoration());
+        }
+        "text_transform" => {
+            styling
+                .as_label_mut()
+                .set_text_transform(value.into_text_transform());
+        }
+        "text_shadow" => {
+            styling
+                .as_label_mut()
+                .set_text_shadow(value.into_box_shadow());
+        }
+        "white_space" => {
+            styling
+                .as_label_mut()
+                .set_white_space(value.into_white_space());
+        }
+        "hyphenate" => {
+            styling.as_label_mut().set_hyphenate(value.into_bool());
+        }
+        "column_count" => {
+            let column_count = evaluator.ensure_unsigned(super::Value { value, location });
+            styling.as_label_mut().set_column_count(column_count);
         }
         "text_color" => {
             styling.as_label_mut().set_text_color(value.into_color());
@@ -97,6 +203,44 @@ fn assign_to_field(
         "padding" => {
             styling.as_base_mut().set_padding(value.into_thickness());
         }
+        "border" => {
+            styling.as_base_mut().set_border(value.into_border());
+        }
+        "border_radius" => {
+            styling
+                .as_base_mut()
+                .set_border_radius(value.into_style_unit());
+        }
+        "box_shadow" => {
+            styling
+                .as_base_mut()
+                .set_box_shadow(value.into_box_shadow());
+        }
+        "opacity" => {
+            styling.as_base_mut().set_opacity(value.into_float());
+        }
+        "transform" => {
+            styling.as_base_mut().set_transform(value.into_transform());
+        }
+        "transform_origin" => {
+            styling
+                .as_base_mut()
+                .set_transform_origin(value.into_position());
+        }
+        "columns" => {
+            styling
+                .as_grid_mut()
+                .set_columns(value.into_grid_cell_sizes());
+        }
+        "rows" => {
+            styling.as_grid_mut().set_rows(value.into_grid_cell_sizes());
+        }
+        "direction" => {
+            styling
+                .as_flex_mut()
+                .set_direction(value.into_flex_direction());
+        }
         name => unreachable!("UNknown {name}"),
     }
+    evaluator.styling = Some(styling);
 }
@@ -22,6 +22,7 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Parameter {
     pub id: VariableId,
+    pub type_: TypeId,
     pub value: Option<Value>,
 }
 
@@ -29,6 +30,8 @@ pub struct Parameter {
 pub struct UserFunctionValue {
     pub has_implicit_slide_parameter: bool,
     pub parameters: Vec<Parameter>,
+    /// The parameter, which receives the elements of a trailing body.
+    pub children_parameter: Option<VariableId>,
     pub body: Vec<BoundNode>,
     pub return_type: TypeId,
 }
@@ -42,6 +45,7 @@ summum! {
     pub enum Value {
         Void(()),
         None(None),
+        Bool(bool),
         Float(f64),
         Integer(i64),
         String(String),
@@ -58,6 +62,13 @@ summum! {
         VerticalAlignment(slides_rs_core::VerticalAlignment),
         HorizontalAlignment(slides_rs_core::HorizontalAlignment),
         TextAlign(slides_rs_core::TextAlign),
+        FontStyle(slides_rs_core::FontStyle),
+        TextDecoration(slides_rs_core::TextDecoration),
+        TextTransform(slides_rs_core::TextTransform),
+        WhiteSpace(slides_rs_core::WhiteSpace),
+        FlexDirection(slides_rs_core::FlexDirection),
+        BackgroundSize(slides_rs_core::BackgroundSize),
+        GridCellSizes(Vec<slides_rs_core::GridCellSize>),
         Font(slides_rs_core::Font),
         StyleUnit(slides_rs_core::StyleUnit),
         Dict(HashMap<String, Value>),
@@ -70,6 +81,11 @@ summum! {
         TextStyling(Arc<RwLock<slides_rs_core::TextStyling>>),
         Element(slides_rs_core::Element),
         Position(slides_rs_core::Position),
+        Angle(slides_rs_core::Angle),
+        ColorStop(slides_rs_core::ColorStop),
+        Border(slides_rs_core::Border),
+        BoxShadow(slides_rs_core::BoxShadow),
+        Transform(slides_rs_core::Transform),
         Module(Arc<RwLock<Module>>),
     }
 }
@@ -79,10 +95,42 @@ impl Value {
         Value::None(None {})
     }
 
+    pub fn equals(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::None(_), Value::None(_)) => true,
+            (Value::Bool(lhs), Value::Bool(rhs)) => lhs == rhs,
+            (Value::Integer(lhs), Value::Integer(rhs)) => lhs == rhs,
+            (Value::Float(lhs), Value::Float(rhs)) => lhs == rhs,
+            (Value::String(lhs), Value::String(rhs)) => lhs == rhs,
+            (Value::Path(lhs), Value::Path(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+
+    /// The variant of an enum value, as it is written in match arms.
+    pub fn into_variant_name(self) -> String {
+        let variant: &'static str = match self {
+            // Variants of user defined enums are strings already.
+            Value::String(variant) => return variant,
+            Value::ObjectFit(it) => it.into(),
+            Value::HorizontalAlignment(it) => it.into(),
+            Value::VerticalAlignment(it) => it.into(),
+            Value::TextAlign(it) => it.into(),
+            Value::FontStyle(it) => it.into(),
+            Value::TextDecoration(it) => it.into(),
+            Value::TextTransform(it) => it.into(),
+            Value::WhiteSpace(it) => it.into(),
+            Value::FlexDirection(it) => it.into(),
+            _ => unreachable!("Binder only allows matching on enums"),
+        };
+        variant.into()
+    }
+
     pub fn infer_type(&self) -> Type {
         match self {
             Value::Void(()) => Type::Void,
             Value::None(_) => Type::None,
+            Value::Bool(_) => Type::Bool,
             Value::Float(_) => Type::Float,
             Value::Integer(_) => Type::Integer,
             Value::String(_) => Type::String,
@@ -100,6 +148,13 @@ impl Value {
             Value::VerticalAlignment(_) => Type::VAlign,
             Value::HorizontalAlignment(_) => Type::HAlign,
             Value::TextAlign(_) => Type::TextAlign,
+            Value::FontStyle(_) => Type::FontStyle,
+            Value::TextDecoration(_) => Type::TextDecoration,
+            Value::TextTransform(_) => Type::TextTransform,
+            Value::WhiteSpace(_) => Type::WhiteSpace,
+            Value::FlexDirection(_) => Type::FlexDirection,
+            Value::BackgroundSize(_) => Type::BackgroundSize,
+            Value::GridCellSizes(_) => Type::GridCellSizes,
             Value::Font(_) => Type::Font,
             Value::StyleUnit(_) => Type::StyleUnit,
             Value::UserFunction(_) => todo!(),
@@ -111,6 +166,11 @@ impl Value {
             Value::Animation(_) => Type::Animation,
             Value::Position(_) => Type::Position,
             Value::Module(_) => Type::Module(crate::ModuleIndex::ANY),
+            Value::Angle(_) => Type::Angle,
+            Value::ColorStop(_) => Type::ColorStop,
+            Value::Border(_) => Type::Border,
+            Value::BoxShadow(_) => Type::BoxShadow,
+            Value::Transform(_) => Type::Transform,
         }
     }
 
@@ -148,9 +208,14 @@ impl Value {
         match self {
             Value::None(_) => String::new(),
             Value::String(string) => string,
+            Value::Bool(bool) => bool.to_string(),
             Value::Float(float) => float.to_string(),
             Value::Integer(int) => int.to_string(),
             Value::StyleUnit(style_unit) => style_unit.to_string(),
+            Value::Angle(angle) => angle.to_string(),
+            Value::ColorStop(color_stop) => color_stop.to_string(),
+            Value::BoxShadow(box_shadow) => box_shadow.to_string(),
+            Value::Transform(transform) => transform.to_css(),
             Value::Color(color) => color.to_string(),
             Value::Path(path) => path.to_string_lossy().to_string(),
             Value::Void(_) => unreachable!(),
@@ -165,11 +230,19 @@ impl Value {
             Value::VerticalAlignment(vertical_alignment) => todo!(),
             Value::HorizontalAlignment(horizontal_alignment) => todo!(),
             Value::TextAlign(text_align) => todo!(),
+            Value::FontStyle(_) => unreachable!("binder rejects FontStyle as string"),
+            Value::TextDecoration(_) => unreachable!("binder rejects TextDecoration as string"),
+            Value::TextTransform(_) => unreachable!("binder rejects TextTransform as string"),
+            Value::WhiteSpace(_) => unreachable!("binder rejects WhiteSpace as string"),
+            Value::FlexDirection(_) => unreachable!("binder rejects FlexDirection as string"),
+            Value::BackgroundSize(_) => unreachable!("binder rejects BackgroundSize as string"),
+            Value::GridCellSizes(_) => unreachable!("binder rejects GridCellSizes as string"),
             Value::Font(font) => todo!(),
             Value::Dict(hash_map) => todo!(),
             Value::UserFunction(user_function_value) => todo!(),
             Value::CustomElement(custom_element) => todo!(),
             Value::Thickness(thickness) => todo!(),
+            Value::Border(_) => unreachable!("binder rejects Border as string"),
             Value::Array(values) => todo!(),
             Value::Filter(filter) => todo!(),
             Value::Animation(animation) => todo!(),
@@ -200,6 +273,16 @@ impl Value {
         }
     }
 
+    pub fn as_color_stop_array(&self) -> Vec<slides_rs_core::ColorStop> {
+        match self {
+            Value::Array(values) => values
+                .into_iter()
+                .map(|v| v.as_color_stop().clone())
+                .collect(),
+            _ => unreachable!("Value is not a color stop array!"),
+        }
+    }
+
     pub fn as_element_array(&self) -> Vec<Element> {
         match self {
             Value::Array(values) => values
@@ -211,7 +294,7 @@ impl Value {
     }
 }
 
-fn parse_multiline_string(text: &str, _replace_escapisms: bool, includes_quotes: bool) -> Value {
+fn parse_multiline_string(text: &str, replace_escapisms: bool, includes_quotes: bool) -> Value {
     let text = if includes_quotes {
         text.strip_suffix("\"\"\"")
             .expect("valid string literal")
@@ -259,10 +342,13 @@ fn parse_multiline_string(text: &str, _replace_escapisms: bool, includes_quotes:
         .next()
         .unwrap_or(result.len());
     result.truncate(trunc);
+    if replace_escapisms {
+        result = resolve_escapes(&result);
+    }
     Value::String(result)
 }
 
-fn parse_single_line_string(text: &str, _replace_escapisms: bool, includes_quotes: bool) -> Value {
+fn parse_single_line_string(text: &str, replace_escapisms: bool, includes_quotes: bool) -> Value {
     let text = if includes_quotes {
         text.strip_suffix('"')
             .expect("valid string literal")
@@ -271,14 +357,53 @@ fn parse_single_line_string(text: &str, _replace_escapisms: bool, includes_quote
     } else {
         text
     };
+    if replace_escapisms {
+        Value::String(resolve_escapes(text))
+    } else {
+        Value::String(text.into())
+    }
+}
+
+/// Resolves the escape sequences `\n`, `\t`, `\r`, `\0`, `\\`, `\"`, `\'`,
+/// `\{`, `\}` and `\u{...}`. Unknown escapes are kept as they are, so that
+/// markdown escapes like `\*` still reach the markdown renderer.
+fn resolve_escapes(text: &str) -> String {
     let mut result = String::with_capacity(text.len());
-    let mut tmp = text.chars();
-    while let Some(ch) = tmp.next() {
-        match ch {
-            _ => result.push(ch),
-        }
+    let mut rest = text;
+    while let Some(index) = rest.find('\\') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+        let (escaped, length) = match rest.chars().next() {
+            Some('n') => ('\n', 1),
+            Some('t') => ('\t', 1),
+            Some('r') => ('\r', 1),
+            Some('0') => ('\0', 1),
+            Some(ch @ ('\\' | '"' | '\'' | '{' | '}')) => (ch, 1),
+            Some('u') => match parse_unicode_escape(&rest[1..]) {
+                Some((ch, length)) => (ch, length + 1),
+                None => ('\\', 0),
+            },
+            _ => ('\\', 0),
+        };
+        result.push(escaped);
+        rest = &rest[length..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Parses the `{1F600}` part of a `\u{1F600}` escape and returns the char and
+/// the number of bytes it spans.
+fn parse_unicode_escape(text: &str) -> Option<(char, usize)> {
+    let (hex, _) = text.strip_prefix('{')?.split_once('}')?;
+    let ch = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+    Some((ch, hex.len() + 2))
+}
+
+impl From<Vec<String>> for Value {
+    fn from(value: Vec<String>) -> Self {
+        Self::Array(value.into_iter().map(Value::String).collect())
     }
-    Value::String(result)
 }
 
 impl From<slides_rs_core::Label> for Value {
@@ -13,7 +13,21 @@ pub enum TokenKind {
     TemplateKeyword,
     LetKeyword,
     GlobalKeyword,
+    EnumKeyword,
+    MatchKeyword,
+    IfKeyword,
+    ElseKeyword,
     NoneKeyword,
+    TrueKeyword,
+    FalseKeyword,
+    SectionKeyword,
+    ExtendsKeyword,
+    RemoveKeyword,
+    LayoutKeyword,
+    UsesKeyword,
+    PropKeyword,
+    UseKeyword,
+    TokensKeyword,
     Number,
     SingleChar(char),
     TwoChars([char; 2]),
@@ -153,7 +167,21 @@ impl Token {
                 "import" => TokenKind::ImportKeyword,
                 "template" => TokenKind::TemplateKeyword,
                 "global" => TokenKind::GlobalKeyword,
+                "enum" => TokenKind::EnumKeyword,
+                "match" => TokenKind::MatchKeyword,
+                "if" => TokenKind::IfKeyword,
+                "else" => TokenKind::ElseKeyword,
                 "none" => TokenKind::NoneKeyword,
+                "true" => TokenKind::TrueKeyword,
+                "false" => TokenKind::FalseKeyword,
+                "section" => TokenKind::SectionKeyword,
+                "extends" => TokenKind::ExtendsKeyword,
+                "remove" => TokenKind::RemoveKeyword,
+                "layout" => TokenKind::LayoutKeyword,
+                "uses" => TokenKind::UsesKeyword,
+                "prop" => TokenKind::PropKeyword,
+                "use" => TokenKind::UseKeyword,
+                "tokens" => TokenKind::TokensKeyword,
                 _ => TokenKind::Identifier,
             };
         }
@@ -235,6 +263,7 @@ fn is_token(char: char) -> bool {
             | '|'
             | '&'
             | '?'
+            | '!'
     )
 }
 
@@ -260,7 +289,7 @@ pub(crate) fn lex_source(location: Location, context: &mut Context) -> Vec<Token
         Number,
         DecimalNumber,
         OneLineString,
-        OneLineFormatString(usize),
+        OneLineFormatString { open_braces: usize, in_string: bool },
         EscapedMultiLineString,
         LineComment,
         SymbolToken(char),
@@ -341,7 +370,10 @@ pub(crate) fn lex_source(location: Location, context: &mut Context) -> Vec<Token
                     finish_trivia(index, &mut current_trivia);
                     current_token = Some(Token::format_string(file, index, current_trivia));
                     current_trivia = Trivia::default();
-                    state = State::OneLineFormatString(0);
+                    state = State::OneLineFormatString {
+                        open_braces: 0,
+                        in_string: false,
+                    };
                     iter.next();
                     is_empty_line = false;
                 }
@@ -394,7 +426,7 @@ pub(crate) fn lex_source(location: Location, context: &mut Context) -> Vec<Token
                     finish_token(index, current_token.take());
                     iter.next();
                 }
-                alphabet if alphabet.is_ascii_alphabetic() => {
+                alphabet if alphabet.is_ascii_alphabetic() || alphabet == '_' => {
                     state = State::Identifier;
                     finish_token(index, current_token.take());
                     finish_trivia(index, &mut current_trivia);
@@ -459,28 +491,51 @@ pub(crate) fn lex_source(location: Location, context: &mut Context) -> Vec<Token
                 state = State::Init;
             }
             State::OneLineString => {
+                if char == '\\' {
+                    skip_escaped_char(&mut iter);
+                    continue;
+                }
                 if char == '"' {
                     state = State::Init;
                 }
                 iter.next();
             }
-            State::OneLineFormatString(open_braces) => {
-                if char == '\'' {
-                    if open_braces != 0 {
-                        todo!("Do we handle {{{{ correctly?");
-                    }
-                    state = State::Init;
-                } else if char == '{' {
-                    state = State::OneLineFormatString(open_braces + 1)
-                } else if char == '}' {
-                    if open_braces == 0 {
-                        todo!("Do we handle }}}} correctly?");
-                    }
-                    state = State::OneLineFormatString(open_braces - 1)
+            State::OneLineFormatString {
+                open_braces,
+                in_string,
+            } => {
+                if char == '\\' {
+                    skip_escaped_char(&mut iter);
+                    continue;
                 }
+                state = match char {
+                    // Strings inside of placeholders may contain braces, which
+                    // should not be counted.
+                    '"' if open_braces > 0 => State::OneLineFormatString {
+                        open_braces,
+                        in_string: !in_string,
+                    },
+                    _ if in_string => state,
+                    // Unbalanced braces are reported by the binder, so the
+                    // string just ends here.
+                    '\'' => State::Init,
+                    '{' => State::OneLineFormatString {
+                        open_braces: open_braces + 1,
+                        in_string,
+                    },
+                    '}' => State::OneLineFormatString {
+                        open_braces: open_braces.saturating_sub(1),
+                        in_string,
+                    },
+                    _ => state,
+                };
                 iter.next();
             }
             State::EscapedMultiLineString => {
+                if char == '\\' {
+                    skip_escaped_char(&mut iter);
+                    continue;
+                }
                 if char == '"' {
                     iter.next();
                     if iter.peek().is_some_and(|&(_, c)| c == '"') {
@@ -515,7 +570,7 @@ pub(crate) fn lex_source(location: Location, context: &mut Context) -> Vec<Token
                 iter.next();
             }
             State::SymbolToken(previous) => match [previous, char] {
-                ['=', '>'] => {
+                ['=', '>'] | ['=', '='] | ['!', '='] | ['?', '?'] => {
                     let file = current_token.unwrap().location.file;
                     let start = current_token.unwrap().location.start;
                     current_token = Some(Token::two_chars_token(
@@ -536,3 +591,13 @@ pub(crate) fn lex_source(location: Location, context: &mut Context) -> Vec<Token
 
     result.into_inner()
 }
+
+/// Consumes a backslash and the char it escapes, so that e.g. `\"` does not
+/// end a string. The escape itself is resolved later on, when the string is
+/// turned into a value.
+fn skip_escaped_char(iter: &mut std::iter::Peekable<impl Iterator<Item = (usize, char)>>) {
+    iter.next();
+    if iter.peek().is_some_and(|&(_, c)| c != '\0') {
+        iter.next();
+    }
+}
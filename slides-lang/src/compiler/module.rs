@@ -29,6 +29,9 @@ use state::State;
 #[derive(Debug, Clone)]
 pub struct ModuleFunction {
     pub name: String,
+    /// Only known for modules, which name their parameters, so that
+    /// arguments can be passed by name.
+    pub parameter_names: Option<Vec<String>>,
     pub type_: FunctionType,
 }
 
@@ -160,6 +163,9 @@ pub fn load_module(
         functions: functions
             .into_iter()
             .map(|f| {
+                let parameter_names = (!f.param_names.is_empty()
+                    && f.param_names.len() == f.args.len())
+                .then_some(f.param_names);
                 let type_ = FunctionType {
                     min_argument_count: f.args.len(),
                     argument_types: f
@@ -173,6 +179,7 @@ pub fn load_module(
                     f.name.clone(),
                     ModuleFunction {
                         name: f.name,
+                        parameter_names,
                         type_,
                     },
                 )
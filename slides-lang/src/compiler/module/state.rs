@@ -113,6 +113,19 @@ impl HostTypeAllocator {
                         typing::Type::TextStyling => return None,
                         typing::Type::Animation => return None,
                         typing::Type::Position => return None,
+                        typing::Type::Angle => return None,
+                        typing::Type::ColorStop => return None,
+                        typing::Type::Border => return None,
+                        typing::Type::BoxShadow => return None,
+                        typing::Type::Transform => return None,
+                        typing::Type::FontStyle => return None,
+                        typing::Type::TextDecoration => return None,
+                        typing::Type::TextTransform => return None,
+                        typing::Type::WhiteSpace => return None,
+                        typing::Type::FlexDirection => return None,
+                        typing::Type::BackgroundSize => return None,
+                        typing::Type::GridCellSizes => return None,
+                        typing::Type::FontSize => return None,
                         t => unreachable!("Not returned by simple types! {t:#?}"),
                     })
                 })
@@ -214,8 +227,9 @@ impl HostValueAllocator {
             value::Value::Float(it) => self.allocate(values::Value::Float(it)),
             value::Value::Integer(it) => self.allocate(values::Value::Int(it)),
             value::Value::String(it) => self.allocate(values::Value::StringType(it)),
+            // Modules can not resolve tokens, so they get the color itself.
             value::Value::Color(color) => {
-                self.allocate(values::Value::StringType(color.to_string()))
+                self.allocate(values::Value::StringType(color.without_token().to_string()))
             }
             value::Value::Dict(hash_map) => {
                 let entries = hash_map
@@ -11,6 +11,7 @@ pub struct StylingStatement {
     pub lparen: Token,
     pub type_: Token,
     pub rparen: Token,
+    pub optional_extends: Option<(Token, Token)>,
     pub colon: Token,
     pub body: Vec<SyntaxNode>,
 }
@@ -35,6 +36,8 @@ pub struct VariableDeclaration {
 pub struct SlideStatement {
     pub slide_keyword: Token,
     pub name: Token,
+    pub optional_extends: Option<(Token, Token)>,
+    pub optional_uses: Option<(Token, Token)>,
     pub colon: Token,
     pub body: Vec<SyntaxNode>,
 }
@@ -68,6 +71,20 @@ pub struct FunctionCall {
     pub lparen: Token,
     pub arguments: Vec<(SyntaxNode, Option<Token>)>,
     pub rparen: Token,
+    pub optional_children: Option<Box<SyntaxNode>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Children {
+    pub colon: Token,
+    pub body: Vec<SyntaxNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NamedArgument {
+    pub name: Token,
+    pub colon: Token,
+    pub value: Box<SyntaxNode>,
 }
 
 #[derive(Debug, Clone)]
@@ -133,6 +150,7 @@ pub struct PostInitialization {
 
 #[derive(Debug, Clone)]
 pub struct TypeNode {
+    pub optional_array: Option<(Token, Token)>,
     pub path: Vec<(Option<Token>, Token)>,
     pub question_mark: Option<Token>,
 }
@@ -151,8 +169,13 @@ impl TypeNode {
         &files[self.location()]
     }
 
-    fn new(path: Vec<(Option<Token>, Token)>, question_mark: Option<Token>) -> Self {
+    fn new(
+        optional_array: Option<(Token, Token)>,
+        path: Vec<(Option<Token>, Token)>,
+        question_mark: Option<Token>,
+    ) -> Self {
         Self {
+            optional_array,
             path,
             question_mark,
         }
@@ -162,8 +185,8 @@ impl TypeNode {
 #[derive(Debug, Clone)]
 pub struct Parameter {
     pub identifier: Token,
-    pub colon: Token,
-    pub type_: TypeNode,
+    pub optional_colon: Option<Token>,
+    pub optional_type: Option<TypeNode>,
     pub optional_equals: Option<Token>,
     pub optional_initializer: Option<Box<SyntaxNode>>,
 }
@@ -185,6 +208,8 @@ pub struct ElementStatement {
 
 #[derive(Debug, Clone)]
 pub struct TemplateStatement {
+    /// Either `template` or `layout`. The parameters of a layout are the
+    /// placeholders a slide fills with its own elements.
     pub template_keyword: Token,
     pub name: Token,
     pub parameters: Box<SyntaxNode>,
@@ -198,6 +223,79 @@ pub struct ImportStatement {
     pub path: Box<SyntaxNode>,
     pub semicolon: Token,
 }
+
+#[derive(Debug, Clone)]
+pub struct UseStatement {
+    pub use_keyword: Token,
+    pub kind: Token,
+    pub name: Token,
+    pub semicolon: Token,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoveStatement {
+    pub remove_keyword: Token,
+    pub name: Token,
+    pub semicolon: Token,
+}
+
+#[derive(Debug, Clone)]
+pub struct SectionStatement {
+    pub section_keyword: Token,
+    pub title: Token,
+    pub colon: Token,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumStatement {
+    pub enum_keyword: Token,
+    pub name: Token,
+    pub colon: Token,
+    pub variants: Vec<(Token, Option<Token>)>,
+    pub optional_semicolon: Option<Token>,
+}
+
+#[derive(Debug, Clone)]
+pub struct If {
+    pub if_keyword: Token,
+    pub optional_let_binding: Option<(Token, Token, Token)>,
+    pub condition: Box<SyntaxNode>,
+    pub colon: Token,
+    pub body: Box<SyntaxNode>,
+    pub optional_else: Option<(Token, Token, Box<SyntaxNode>)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub variant: Token,
+    pub arrow: Token,
+    pub body: Box<SyntaxNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub match_keyword: Token,
+    pub value: Box<SyntaxNode>,
+    pub colon: Token,
+    pub arms: Vec<(SyntaxNode, Option<Token>)>,
+}
+
+/// An arm of a `match` statement, whose body is a block of statements.
+#[derive(Debug, Clone)]
+pub struct MatchStatementArm {
+    pub variant: Token,
+    pub colon: Token,
+    pub body: Vec<SyntaxNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchStatement {
+    pub match_keyword: Token,
+    pub value: Box<SyntaxNode>,
+    pub colon: Token,
+    pub arms: Vec<SyntaxNode>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Binary {
     pub lhs: Box<SyntaxNode>,
@@ -213,7 +311,11 @@ pub enum SyntaxNodeKind {
     GlobalStatement(GlobalStatement),
     ElementStatement(ElementStatement),
     ImportStatement(ImportStatement),
+    UseStatement(UseStatement),
     TemplateStatement(TemplateStatement),
+    EnumStatement(EnumStatement),
+    SectionStatement(SectionStatement),
+    RemoveStatement(RemoveStatement),
     ExpressionStatement(ExpressionStatement),
     VariableDeclaration(VariableDeclaration),
     AssignmentStatement(AssignmentStatement),
@@ -221,6 +323,8 @@ pub enum SyntaxNodeKind {
     Literal(Token),
     MemberAccess(MemberAccess),
     FunctionCall(FunctionCall),
+    Children(Children),
+    NamedArgument(NamedArgument),
     TypedString(TypedString),
     DictEntry(DictEntry),
     Dict(Dict),
@@ -233,6 +337,11 @@ pub enum SyntaxNodeKind {
     Parameter(Parameter),
     ParameterBlock(ParameterBlock),
     Binary(Binary),
+    Match(Match),
+    MatchArm(MatchArm),
+    MatchStatement(MatchStatement),
+    MatchStatementArm(MatchStatementArm),
+    If(If),
     FormatString(Token),
 }
 
@@ -249,12 +358,15 @@ impl SyntaxNode {
         lparen: Token,
         type_: Token,
         rparen: Token,
+        optional_extends: Option<(Token, Token)>,
         colon: Token,
         body: Vec<SyntaxNode>,
     ) -> SyntaxNode {
+        // A styling extending another one may consist of its base alone.
         let location = Location::combine(
             styling_keyword.location,
-            body.last().expect("not be empty").location,
+            body.last()
+                .map_or(colon.location, |statement| statement.location),
         );
         SyntaxNode {
             kind: SyntaxNodeKind::StylingStatement(StylingStatement {
@@ -263,6 +375,7 @@ impl SyntaxNode {
                 lparen,
                 type_,
                 rparen,
+                optional_extends,
                 colon,
                 body,
             }),
@@ -306,17 +419,23 @@ impl SyntaxNode {
     fn slide_statement(
         slide_keyword: Token,
         name: Token,
+        optional_extends: Option<(Token, Token)>,
+        optional_uses: Option<(Token, Token)>,
         colon: Token,
         body: Vec<SyntaxNode>,
     ) -> SyntaxNode {
+        // A slide extending another one may consist of its base alone.
         let location = Location::combine(
             slide_keyword.location,
-            body.last().expect("not empty").location,
+            body.last()
+                .map_or(colon.location, |statement| statement.location),
         );
         SyntaxNode {
             kind: SyntaxNodeKind::SlideStatement(SlideStatement {
                 slide_keyword,
                 name,
+                optional_extends,
+                optional_uses,
                 colon,
                 body,
             }),
@@ -403,6 +522,38 @@ impl SyntaxNode {
                 lparen,
                 arguments,
                 rparen,
+                optional_children: None,
+            }),
+            location,
+        }
+    }
+
+    fn with_children(self, colon: Token, body: Vec<SyntaxNode>) -> SyntaxNode {
+        let SyntaxNodeKind::FunctionCall(mut function_call) = self.kind else {
+            unreachable!("Only function calls can have children!");
+        };
+        let children_location = Location::combine(
+            colon.location,
+            body.last().map(|s| s.location).unwrap_or(colon.location),
+        );
+        let location = Location::combine(self.location, children_location);
+        function_call.optional_children = Some(Box::new(SyntaxNode {
+            location: children_location,
+            kind: SyntaxNodeKind::Children(Children { colon, body }),
+        }));
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::FunctionCall(function_call),
+        }
+    }
+
+    fn named_argument(name: Token, colon: Token, value: SyntaxNode) -> SyntaxNode {
+        let location = Location::combine(name.location, value.location);
+        SyntaxNode {
+            kind: SyntaxNodeKind::NamedArgument(NamedArgument {
+                name,
+                colon,
+                value: Box::new(value),
             }),
             location,
         }
@@ -496,18 +647,21 @@ impl SyntaxNode {
 
     fn parameter(
         identifier: Token,
-        colon: Token,
-        type_: TypeNode,
+        optional_colon: Option<Token>,
+        optional_type: Option<TypeNode>,
         optional_equals: Option<Token>,
         optional_initializer: Option<SyntaxNode>,
     ) -> SyntaxNode {
-        let location = Location::combine(identifier.location, type_.location());
+        let location = match &optional_type {
+            Some(type_) => Location::combine(identifier.location, type_.location()),
+            None => identifier.location,
+        };
         SyntaxNode {
             location,
             kind: SyntaxNodeKind::Parameter(Parameter {
                 identifier,
-                colon,
-                type_,
+                optional_colon,
+                optional_type,
                 optional_equals,
                 optional_initializer: optional_initializer.map(Box::new),
             }),
@@ -567,6 +721,19 @@ impl SyntaxNode {
         }
     }
 
+    fn use_statement(use_keyword: Token, kind: Token, name: Token, semicolon: Token) -> SyntaxNode {
+        let location = Location::combine(use_keyword.location, semicolon.location);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::UseStatement(UseStatement {
+                use_keyword,
+                kind,
+                name,
+                semicolon,
+            }),
+        }
+    }
+
     fn template_statement(
         template_keyword: Token,
         name: Token,
@@ -618,6 +785,151 @@ impl SyntaxNode {
         }
     }
 
+    fn enum_statement(
+        enum_keyword: Token,
+        name: Token,
+        colon: Token,
+        variants: Vec<(Token, Option<Token>)>,
+        optional_semicolon: Option<Token>,
+    ) -> SyntaxNode {
+        let end = optional_semicolon
+            .or_else(|| variants.last().map(|(variant, _)| *variant))
+            .unwrap_or(colon);
+        let location = Location::combine(enum_keyword.location, end.location);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::EnumStatement(EnumStatement {
+                enum_keyword,
+                name,
+                colon,
+                variants,
+                optional_semicolon,
+            }),
+        }
+    }
+
+    fn section_statement(section_keyword: Token, title: Token, colon: Token) -> SyntaxNode {
+        let location = Location::combine(section_keyword.location, colon.location);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::SectionStatement(SectionStatement {
+                section_keyword,
+                title,
+                colon,
+            }),
+        }
+    }
+
+    fn remove_statement(remove_keyword: Token, name: Token, semicolon: Token) -> SyntaxNode {
+        let location = Location::combine(remove_keyword.location, semicolon.location);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::RemoveStatement(RemoveStatement {
+                remove_keyword,
+                name,
+                semicolon,
+            }),
+        }
+    }
+
+    fn match_arm(variant: Token, arrow: Token, body: SyntaxNode) -> SyntaxNode {
+        let location = Location::combine(variant.location, body.location);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::MatchArm(MatchArm {
+                variant,
+                arrow,
+                body: Box::new(body),
+            }),
+        }
+    }
+
+    fn match_(
+        match_keyword: Token,
+        value: SyntaxNode,
+        colon: Token,
+        arms: Vec<(SyntaxNode, Option<Token>)>,
+    ) -> SyntaxNode {
+        let location = Location::combine(
+            match_keyword.location,
+            arms.last()
+                .map(|(arm, _)| arm.location)
+                .unwrap_or(colon.location),
+        );
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::Match(Match {
+                match_keyword,
+                value: Box::new(value),
+                colon,
+                arms,
+            }),
+        }
+    }
+
+    fn if_(
+        if_keyword: Token,
+        optional_let_binding: Option<(Token, Token, Token)>,
+        condition: SyntaxNode,
+        colon: Token,
+        body: SyntaxNode,
+        optional_else: Option<(Token, Token, SyntaxNode)>,
+    ) -> SyntaxNode {
+        let location = Location::combine(
+            if_keyword.location,
+            optional_else
+                .as_ref()
+                .map(|(_, _, body)| body.location)
+                .unwrap_or(body.location),
+        );
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::If(If {
+                if_keyword,
+                optional_let_binding,
+                condition: Box::new(condition),
+                colon,
+                body: Box::new(body),
+                optional_else: optional_else
+                    .map(|(else_keyword, colon, body)| (else_keyword, colon, Box::new(body))),
+            }),
+        }
+    }
+
+    fn match_statement_arm(variant: Token, colon: Token, body: Vec<SyntaxNode>) -> SyntaxNode {
+        let end = body
+            .last()
+            .map_or(colon.location, |statement| statement.location);
+        let location = Location::combine(variant.location, end);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::MatchStatementArm(MatchStatementArm {
+                variant,
+                colon,
+                body,
+            }),
+        }
+    }
+
+    fn match_statement(
+        match_keyword: Token,
+        value: SyntaxNode,
+        colon: Token,
+        arms: Vec<SyntaxNode>,
+    ) -> SyntaxNode {
+        let end = arms.last().map_or(colon.location, |arm| arm.location);
+        let location = Location::combine(match_keyword.location, end);
+        SyntaxNode {
+            location,
+            kind: SyntaxNodeKind::MatchStatement(MatchStatement {
+                match_keyword,
+                value: Box::new(value),
+                colon,
+                arms,
+            }),
+        }
+    }
+
     fn lambda(parameter: SyntaxNode, arrow: Token, body: SyntaxNode) -> SyntaxNode {
         let location = Location::combine(parameter.location, body.location);
         SyntaxNode {
@@ -646,11 +958,15 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
     print!("{indent}");
     match &node.kind {
         SyntaxNodeKind::StylingStatement(styling_statement) => {
-            println!(
-                "Styling {} for {}:",
+            print!(
+                "Styling {} for {}",
                 styling_statement.name.text(files),
                 styling_statement.type_.text(files)
             );
+            if let Some((_, base)) = styling_statement.optional_extends {
+                print!(" extends {}", base.text(files));
+            }
+            println!(":");
             for statement in &styling_statement.body {
                 debug_syntax_node(statement, files, format!("{indent}    "));
             }
@@ -679,6 +995,18 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
                 debug_syntax_node(statement, files, format!("{indent}        "));
             }
         }
+        SyntaxNodeKind::SectionStatement(section_statement) => {
+            println!("Section {}", section_statement.title.text(files));
+        }
+        SyntaxNodeKind::RemoveStatement(remove_statement) => {
+            println!("Remove {}", remove_statement.name.text(files));
+        }
+        SyntaxNodeKind::EnumStatement(enum_statement) => {
+            println!("Enum {}", enum_statement.name.text(files));
+            for (variant, _) in &enum_statement.variants {
+                println!("{indent}    {}", variant.text(files));
+            }
+        }
         SyntaxNodeKind::ExpressionStatement(expression_statement) => {
             println!("ExpressionStatement:");
             debug_syntax_node(
@@ -699,13 +1027,24 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
             );
         }
         SyntaxNodeKind::SlideStatement(slide_statement) => {
-            println!("Slide Declaration {}:", slide_statement.name.text(files));
+            print!("Slide Declaration {}", slide_statement.name.text(files));
+            if let Some((_, base)) = slide_statement.optional_extends {
+                print!(" extends {}", base.text(files));
+            }
+            if let Some((_, layout)) = slide_statement.optional_uses {
+                print!(" uses {}", layout.text(files));
+            }
+            println!(":");
             for statement in &slide_statement.body {
                 debug_syntax_node(statement, files, format!("{indent}    "));
             }
         }
         SyntaxNodeKind::GlobalStatement(global_statement) => {
-            println!("Global execution");
+            if global_statement.global_keyword.kind == TokenKind::TokensKeyword {
+                println!("Tokens");
+            } else {
+                println!("Global execution");
+            }
             for statement in &global_statement.body {
                 debug_syntax_node(statement, files, format!("{indent}    "));
             }
@@ -744,6 +1083,19 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
             for (argument, _) in &function_call.arguments {
                 debug_syntax_node(&argument, files, format!("{indent}        "));
             }
+            if let Some(children) = &function_call.optional_children {
+                debug_syntax_node(children, files, format!("{indent}    "));
+            }
+        }
+        SyntaxNodeKind::Children(children) => {
+            println!("Children:");
+            for statement in &children.body {
+                debug_syntax_node(statement, files, format!("{indent}    "));
+            }
+        }
+        SyntaxNodeKind::NamedArgument(named_argument) => {
+            println!("Named Argument {}:", named_argument.name.text(files));
+            debug_syntax_node(&named_argument.value, files, format!("{indent}    "));
         }
         SyntaxNodeKind::TypedString(typed_string) => {
             println!(
@@ -779,13 +1131,14 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
             );
             debug_syntax_node(&post_initialization.dict, files, format!("{indent}    "));
         }
-        SyntaxNodeKind::Parameter(parameter) => {
-            println!(
+        SyntaxNodeKind::Parameter(parameter) => match &parameter.optional_type {
+            Some(type_) => println!(
                 "{}: {}",
                 parameter.identifier.text(files),
-                parameter.type_.text(files)
-            )
-        }
+                type_.text(files)
+            ),
+            None => println!("{}", parameter.identifier.text(files)),
+        },
         SyntaxNodeKind::ParameterBlock(parameter_block) => {
             println!("Parameters");
             for (parameter, _) in &parameter_block.parameters {
@@ -796,6 +1149,13 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
             println!("Import");
             debug_syntax_node(&import_statement.path, files, format!("{indent}    "));
         }
+        SyntaxNodeKind::UseStatement(use_statement) => {
+            println!(
+                "Use {} {}",
+                use_statement.kind.text(files),
+                use_statement.name.text(files)
+            );
+        }
         SyntaxNodeKind::Array(array) => {
             println!("Array");
             for (entry, _) in &array.entries {
@@ -816,6 +1176,41 @@ fn debug_syntax_node(node: &SyntaxNode, files: &Files, indent: String) {
             debug_syntax_node(&lambda.parameter, files, format!("{indent}    "));
             debug_syntax_node(&lambda.body, files, format!("{indent}  =>"));
         }
+        SyntaxNodeKind::Match(match_) => {
+            println!("Match");
+            debug_syntax_node(&match_.value, files, format!("{indent}    "));
+            for (arm, _) in &match_.arms {
+                debug_syntax_node(arm, files, format!("{indent}    "));
+            }
+        }
+        SyntaxNodeKind::MatchArm(match_arm) => {
+            println!("Arm {}", match_arm.variant.text(files));
+            debug_syntax_node(&match_arm.body, files, format!("{indent}  =>"));
+        }
+        SyntaxNodeKind::MatchStatement(match_statement) => {
+            println!("MatchStatement");
+            debug_syntax_node(&match_statement.value, files, format!("{indent}    "));
+            for arm in &match_statement.arms {
+                debug_syntax_node(arm, files, format!("{indent}    "));
+            }
+        }
+        SyntaxNodeKind::MatchStatementArm(match_statement_arm) => {
+            println!("Arm {}:", match_statement_arm.variant.text(files));
+            for statement in &match_statement_arm.body {
+                debug_syntax_node(statement, files, format!("{indent}    "));
+            }
+        }
+        SyntaxNodeKind::If(if_) => {
+            match &if_.optional_let_binding {
+                Some((_, name, _)) => println!("If let {}", name.text(files)),
+                None => println!("If"),
+            }
+            debug_syntax_node(&if_.condition, files, format!("{indent}    "));
+            debug_syntax_node(&if_.body, files, format!("{indent}  then "));
+            if let Some((_, _, body)) = &if_.optional_else {
+                debug_syntax_node(body, files, format!("{indent}  else "));
+            }
+        }
     }
 }
 
@@ -860,6 +1255,18 @@ impl Parser {
         TokenKind::Eof
     }
 
+    /// Checks whether the `match` at the current token is followed by arms
+    /// in the statement form `Variant:`.
+    fn is_match_statement(&self) -> bool {
+        let Some(colon) = (self.index + 1..self.tokens.len())
+            .find(|&index| self.tokens[index].kind == TokenKind::SingleChar(':'))
+        else {
+            return false;
+        };
+        self.tokens.get(colon + 1).map(|t| t.kind) == Some(TokenKind::Identifier)
+            && self.tokens.get(colon + 2).map(|t| t.kind) == Some(TokenKind::SingleChar(':'))
+    }
+
     fn position(&self) -> usize {
         self.index
     }
@@ -913,9 +1320,16 @@ fn parse_top_level_statement(parser: &mut Parser, context: &mut Context) -> Synt
         TokenKind::SlideKeyword => parse_slide_statement(parser, context),
         TokenKind::StylingKeyword => parse_styling_statement(parser, context),
         TokenKind::ElementKeyword => parse_element_statement(parser, context),
-        TokenKind::TemplateKeyword => parse_template_statement(parser, context),
+        TokenKind::TemplateKeyword | TokenKind::LayoutKeyword => {
+            parse_template_statement(parser, context)
+        }
         TokenKind::ImportKeyword => parse_import_statement(parser, context),
-        TokenKind::GlobalKeyword => parse_global_statement(parser, context),
+        TokenKind::UseKeyword => parse_use_statement(parser, context),
+        TokenKind::GlobalKeyword | TokenKind::TokensKeyword => {
+            parse_global_statement(parser, context)
+        }
+        TokenKind::EnumKeyword => parse_enum_statement(parser, context),
+        TokenKind::SectionKeyword => parse_section_statement(parser, context),
         _ => {
             context
                 .diagnostics
@@ -926,9 +1340,11 @@ fn parse_top_level_statement(parser: &mut Parser, context: &mut Context) -> Synt
 }
 
 fn parse_template_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
-    let template_keyword = parser.match_token(TokenKind::TemplateKeyword, &mut context.diagnostics);
+    let template_keyword = parser.next_token();
+    let is_layout = template_keyword.kind == TokenKind::LayoutKeyword;
     let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
-    let parameters = parse_parameter_node(parser, context);
+    // Placeholders are elements unless stated otherwise.
+    let parameters = parse_parameter_node(parser, context, !is_layout);
     let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
     let mut body = Vec::new();
     while !is_start_of_top_level_statement(parser.current_token().kind) {
@@ -951,8 +1367,45 @@ fn parse_import_statement(parser: &mut Parser, context: &mut Context) -> SyntaxN
     SyntaxNode::import_statement(import_keyword, path, semicolon)
 }
 
+fn parse_use_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let use_keyword = parser.match_token(TokenKind::UseKeyword, &mut context.diagnostics);
+    let kind = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+    let name = parser.match_token(TokenKind::String, &mut context.diagnostics);
+    let semicolon = parser.match_token(TokenKind::SingleChar(';'), &mut context.diagnostics);
+    SyntaxNode::use_statement(use_keyword, kind, name, semicolon)
+}
+
+fn parse_section_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let section_keyword = parser.match_token(TokenKind::SectionKeyword, &mut context.diagnostics);
+    let title = parser.match_token(TokenKind::String, &mut context.diagnostics);
+    let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+    SyntaxNode::section_statement(section_keyword, title, colon)
+}
+
+fn parse_enum_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let enum_keyword = parser.match_token(TokenKind::EnumKeyword, &mut context.diagnostics);
+    let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+    let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+    let mut variants = Vec::new();
+    loop {
+        let variant = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+        let optional_comma = parser.try_match_token(TokenKind::SingleChar(','));
+        let is_last = optional_comma.is_none();
+        variants.push((variant, optional_comma));
+        if is_last || parser.current_token().kind != TokenKind::Identifier {
+            break;
+        }
+    }
+    let optional_semicolon = parser.try_match_token(TokenKind::SingleChar(';'));
+    SyntaxNode::enum_statement(enum_keyword, name, colon, variants, optional_semicolon)
+}
+
 fn parse_global_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
-    let slide_keyword = parser.match_token(TokenKind::GlobalKeyword, &mut context.diagnostics);
+    // Tokens are declared like globals.
+    let slide_keyword = match parser.try_match_token(TokenKind::TokensKeyword) {
+        Some(tokens_keyword) => tokens_keyword,
+        None => parser.match_token(TokenKind::GlobalKeyword, &mut context.diagnostics),
+    };
     let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
     let mut body = Vec::new();
     while !is_start_of_top_level_statement(parser.current_token().kind) {
@@ -970,7 +1423,7 @@ fn parse_global_statement(parser: &mut Parser, context: &mut Context) -> SyntaxN
 fn parse_element_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
     let element_keyword = parser.match_token(TokenKind::ElementKeyword, &mut context.diagnostics);
     let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
-    let parameters = parse_parameter_node(parser, context);
+    let parameters = parse_parameter_node(parser, context, true);
     let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
     let mut body = Vec::new();
     while !is_start_of_top_level_statement(parser.current_token().kind) {
@@ -984,7 +1437,11 @@ fn parse_element_statement(parser: &mut Parser, context: &mut Context) -> Syntax
     SyntaxNode::element_statement(element_keyword, name, parameters, colon, body)
 }
 
-fn parse_parameter_node(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+fn parse_parameter_node(
+    parser: &mut Parser,
+    context: &mut Context,
+    is_type_required: bool,
+) -> SyntaxNode {
     let lparen = parser.match_token(TokenKind::SingleChar('('), &mut context.diagnostics);
     let mut parameters = Vec::new();
     while parser.current_token().kind != TokenKind::Eof
@@ -992,26 +1449,9 @@ fn parse_parameter_node(parser: &mut Parser, context: &mut Context) -> SyntaxNod
     {
         let position = parser.position();
 
-        let identifier = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
-        let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
-        let type_ = parse_type(parser, context);
-        let optional_equals = parser.try_match_token(TokenKind::SingleChar('='));
-        let optional_initializer = if optional_equals.is_some() {
-            Some(parse_expression(parser, context))
-        } else {
-            None
-        };
+        let parameter = parse_parameter(parser, context, is_type_required);
         let optional_comma = parser.try_match_token(TokenKind::SingleChar(','));
-        parameters.push((
-            SyntaxNode::parameter(
-                identifier,
-                colon,
-                type_,
-                optional_equals,
-                optional_initializer,
-            ),
-            optional_comma,
-        ));
+        parameters.push((parameter, optional_comma));
         if let Some(consumed) = parser.ensure_consume(position) {
             parameters.push((SyntaxNode::error(consumed, true), None));
         }
@@ -1020,9 +1460,50 @@ fn parse_parameter_node(parser: &mut Parser, context: &mut Context) -> SyntaxNod
     SyntaxNode::parameter_block(lparen, parameters, rparen)
 }
 
+fn parse_parameter(
+    parser: &mut Parser,
+    context: &mut Context,
+    is_type_required: bool,
+) -> SyntaxNode {
+    let identifier = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+    let optional_colon = if is_type_required {
+        Some(parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics))
+    } else {
+        parser.try_match_token(TokenKind::SingleChar(':'))
+    };
+    let optional_type = optional_colon.map(|_| parse_type(parser, context));
+    let optional_equals = parser.try_match_token(TokenKind::SingleChar('='));
+    let optional_initializer = if optional_equals.is_some() {
+        Some(parse_expression(parser, context))
+    } else {
+        None
+    };
+    SyntaxNode::parameter(
+        identifier,
+        optional_colon,
+        optional_type,
+        optional_equals,
+        optional_initializer,
+    )
+}
+
 fn parse_slide_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
     let slide_keyword = parser.match_token(TokenKind::SlideKeyword, &mut context.diagnostics);
     let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+    let optional_extends = if parser.current_token().kind == TokenKind::ExtendsKeyword {
+        let extends_keyword = parser.next_token();
+        let base = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+        Some((extends_keyword, base))
+    } else {
+        None
+    };
+    let optional_uses = if parser.current_token().kind == TokenKind::UsesKeyword {
+        let uses_keyword = parser.next_token();
+        let layout = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+        Some((uses_keyword, layout))
+    } else {
+        None
+    };
     let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
     let mut body = Vec::new();
     while !is_start_of_top_level_statement(parser.current_token().kind) {
@@ -1034,7 +1515,14 @@ fn parse_slide_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNo
         }
     }
 
-    SyntaxNode::slide_statement(slide_keyword, name, colon, body)
+    SyntaxNode::slide_statement(
+        slide_keyword,
+        name,
+        optional_extends,
+        optional_uses,
+        colon,
+        body,
+    )
 }
 
 fn parse_styling_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
@@ -1043,6 +1531,13 @@ fn parse_styling_statement(parser: &mut Parser, context: &mut Context) -> Syntax
     let lparen = parser.match_token(TokenKind::SingleChar('('), &mut context.diagnostics);
     let type_ = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
     let rparen = parser.match_token(TokenKind::SingleChar(')'), &mut context.diagnostics);
+    let optional_extends = if parser.current_token().kind == TokenKind::ExtendsKeyword {
+        let extends_keyword = parser.next_token();
+        let base = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+        Some((extends_keyword, base))
+    } else {
+        None
+    };
     let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
     let mut body = Vec::new();
     while !is_start_of_top_level_statement(parser.current_token().kind) {
@@ -1053,33 +1548,63 @@ fn parse_styling_statement(parser: &mut Parser, context: &mut Context) -> Syntax
         }
     }
 
-    SyntaxNode::styling_statement(styling_keyword, name, lparen, type_, rparen, colon, body)
+    SyntaxNode::styling_statement(
+        styling_keyword,
+        name,
+        lparen,
+        type_,
+        rparen,
+        optional_extends,
+        colon,
+        body,
+    )
 }
 
 fn parse_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
     match parser.current_token().kind {
-        TokenKind::LetKeyword => parse_variable_declaration(parser, context),
+        TokenKind::LetKeyword | TokenKind::PropKeyword => {
+            parse_variable_declaration(parser, context)
+        }
+        TokenKind::RemoveKeyword => parse_remove_statement(parser, context),
+        TokenKind::MatchKeyword if parser.is_match_statement() => {
+            parse_match_statement(parser, context)
+        }
         _ => parse_assignment_statemnt(parser, context),
     }
 }
 
+fn parse_remove_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let remove_keyword = parser.match_token(TokenKind::RemoveKeyword, &mut context.diagnostics);
+    let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+    let semicolon = parser.match_token(TokenKind::SingleChar(';'), &mut context.diagnostics);
+    SyntaxNode::remove_statement(remove_keyword, name, semicolon)
+}
+
 fn parse_assignment_statemnt(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let indentation = context.loaded_files[parser.current_token().location.file]
+        .column(parser.current_token().location.start);
     let expression = parse_expression(parser, context);
     if parser.current_token().kind == TokenKind::SingleChar('=') {
         let equals = parser.next_token();
         let assignment = parse_expression(parser, context);
-        let semicolon = parser.match_token(TokenKind::SingleChar(';'), &mut context.diagnostics);
+        let assignment = parse_optional_children(assignment, indentation, parser, context);
+        let semicolon = match_statement_end(&assignment, parser, context);
 
         SyntaxNode::assignment_statement(expression, equals, assignment, semicolon)
     } else {
-        let semicolon = parser.match_token(TokenKind::SingleChar(';'), &mut context.diagnostics);
+        let expression = parse_optional_children(expression, indentation, parser, context);
+        let semicolon = match_statement_end(&expression, parser, context);
 
         SyntaxNode::expression_statement(expression, semicolon)
     }
 }
 
 fn parse_variable_declaration(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
-    let let_keyword = parser.match_token(TokenKind::LetKeyword, &mut context.diagnostics);
+    // Properties of elements are declared like variables.
+    let let_keyword = match parser.try_match_token(TokenKind::PropKeyword) {
+        Some(prop_keyword) => prop_keyword,
+        None => parser.match_token(TokenKind::LetKeyword, &mut context.diagnostics),
+    };
     let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
     let optional_type_declaration = if parser.current_token().kind == TokenKind::SingleChar(':') {
         let colon_token = parser.next_token();
@@ -1090,7 +1615,10 @@ fn parse_variable_declaration(parser: &mut Parser, context: &mut Context) -> Syn
     };
     let equals = parser.match_token(TokenKind::SingleChar('='), &mut context.diagnostics);
     let expression = parse_expression(parser, context);
-    let semicolon = parser.match_token(TokenKind::SingleChar(';'), &mut context.diagnostics);
+    let indentation =
+        context.loaded_files[let_keyword.location.file].column(let_keyword.location.start);
+    let expression = parse_optional_children(expression, indentation, parser, context);
+    let semicolon = match_statement_end(&expression, parser, context);
 
     SyntaxNode::variable_declaration(
         let_keyword,
@@ -1102,7 +1630,70 @@ fn parse_variable_declaration(parser: &mut Parser, context: &mut Context) -> Syn
     )
 }
 
+/// Parses the trailing body of a function call like `card("Title"):`. The
+/// body consists of all following statements, which are indented further
+/// than the statement the call belongs to.
+fn parse_optional_children(
+    expression: SyntaxNode,
+    indentation: usize,
+    parser: &mut Parser,
+    context: &mut Context,
+) -> SyntaxNode {
+    if parser.current_token().kind != TokenKind::SingleChar(':')
+        || expression.kind.try_as_function_call_ref().is_none()
+    {
+        return expression;
+    }
+    let colon = parser.next_token();
+    let mut body = Vec::new();
+    loop {
+        let location = parser.current_token().location;
+        if is_start_of_top_level_statement(parser.current_token().kind)
+            || context.loaded_files[location.file].column(location.start) <= indentation
+        {
+            break;
+        }
+        let position = parser.position();
+        body.push(parse_statement(parser, context));
+        if let Some(consumed) = parser.ensure_consume(position) {
+            body.push(SyntaxNode::error(consumed, true));
+        }
+    }
+    expression.with_children(colon, body)
+}
+
+/// Statements ending in a body of children do not need a semicolon.
+fn match_statement_end(
+    expression: &SyntaxNode,
+    parser: &mut Parser,
+    context: &mut Context,
+) -> Token {
+    let has_children = expression
+        .kind
+        .try_as_function_call_ref()
+        .is_some_and(|f| f.optional_children.is_some());
+    if has_children {
+        parser
+            .try_match_token(TokenKind::SingleChar(';'))
+            .unwrap_or_else(|| {
+                let mut location = expression.location;
+                location.start += location.length;
+                Token::fabricate(TokenKind::SingleChar(';'), location)
+            })
+    } else {
+        parser.match_token(TokenKind::SingleChar(';'), &mut context.diagnostics)
+    }
+}
+
 fn parse_type(parser: &mut Parser, context: &mut Context) -> TypeNode {
+    let optional_array = if parser.current_token().kind == TokenKind::Identifier
+        && parser.current_token().text(&context.loaded_files) == "Array"
+        && parser.peek() == TokenKind::SingleChar(':')
+    {
+        Some((parser.next_token(), parser.next_token()))
+    } else {
+        None
+    };
     let mut path = Vec::new();
     let token = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
     path.push((None, token));
@@ -1116,11 +1707,33 @@ fn parse_type(parser: &mut Parser, context: &mut Context) -> TypeNode {
     } else {
         None
     };
-    TypeNode::new(path, question_mark)
+    TypeNode::new(optional_array, path, question_mark)
 }
 
 fn parse_expression(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
-    parse_mul_div(parser, context)
+    parse_coalesce(parser, context)
+}
+
+fn parse_coalesce(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let mut lhs = parse_comparison(parser, context);
+    while parser.current_token().kind == TokenKind::TwoChars(['?', '?']) {
+        let operator = parser.next_token();
+        let rhs = parse_comparison(parser, context);
+        lhs = SyntaxNode::binary(lhs, operator, rhs);
+    }
+    lhs
+}
+
+fn parse_comparison(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let mut lhs = parse_mul_div(parser, context);
+    while parser.current_token().kind == TokenKind::TwoChars(['=', '='])
+        || parser.current_token().kind == TokenKind::TwoChars(['!', '='])
+    {
+        let operator = parser.next_token();
+        let rhs = parse_mul_div(parser, context);
+        lhs = SyntaxNode::binary(lhs, operator, rhs);
+    }
+    lhs
 }
 
 fn parse_mul_div(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
@@ -1179,7 +1792,16 @@ fn parse_function_call(parser: &mut Parser, context: &mut Context) -> SyntaxNode
                 while parser.current_token().kind != TokenKind::SingleChar(')') {
                     let start = parser.position();
 
-                    let argument = parse_expression(parser, context);
+                    let argument = if parser.current_token().kind == TokenKind::Identifier
+                        && parser.peek() == TokenKind::SingleChar(':')
+                    {
+                        let name = parser.next_token();
+                        let colon = parser.next_token();
+                        let value = parse_expression(parser, context);
+                        SyntaxNode::named_argument(name, colon, value)
+                    } else {
+                        parse_expression(parser, context)
+                    };
                     let optional_comma = parser.try_match_token(TokenKind::SingleChar(','));
 
                     arguments.push((argument, optional_comma));
@@ -1215,6 +1837,8 @@ fn parse_primary(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
         TokenKind::Identifier => {
             if parser.peek() == TokenKind::String || parser.peek() == TokenKind::FormatString {
                 SyntaxNode::typed_string(parser.next_token(), parser.next_token())
+            } else if parser.peek() == TokenKind::TwoChars(['=', '>']) {
+                parse_lambda(parser, context)
             } else {
                 SyntaxNode::variable_reference(parser.next_token())
             }
@@ -1227,14 +1851,24 @@ fn parse_primary(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
                 let unit = parser.next_token();
                 match Token::combine(number, unit, TokenKind::StyleUnitLiteral) {
                     Ok(token) => SyntaxNode::literal(token),
-                    Err(err) => SyntaxNode::error(err, true),
+                    Err(err) => {
+                        context.diagnostics.report_separated_unit(err.location);
+                        SyntaxNode::error(err, true)
+                    }
                 }
             } else {
                 SyntaxNode::literal(number)
             }
         }
+        // `slide("name")` looks up a slide, everywhere else `slide` starts a
+        // slide statement.
+        TokenKind::SlideKeyword if parser.peek() == TokenKind::SingleChar('(') => {
+            SyntaxNode::variable_reference(parser.next_token())
+        }
         TokenKind::String => SyntaxNode::literal(parser.next_token()),
-        TokenKind::NoneKeyword => SyntaxNode::literal(parser.next_token()),
+        TokenKind::NoneKeyword | TokenKind::TrueKeyword | TokenKind::FalseKeyword => {
+            SyntaxNode::literal(parser.next_token())
+        }
         TokenKind::FormatString => SyntaxNode::format_string(parser.next_token()),
         TokenKind::SingleChar('{') => parse_dict(parser, context),
         TokenKind::SingleChar('[') => parse_array(parser, context),
@@ -1249,6 +1883,8 @@ fn parse_primary(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
         }
 
         TokenKind::SingleChar('.') => parse_inferred_member(parser, context),
+        TokenKind::MatchKeyword => parse_match(parser, context),
+        TokenKind::IfKeyword => parse_if(parser, context),
         _ => {
             context
                 .diagnostics
@@ -1258,8 +1894,107 @@ fn parse_primary(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
     }
 }
 
+fn parse_match(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let match_keyword = parser.match_token(TokenKind::MatchKeyword, &mut context.diagnostics);
+    let value = parse_expression(parser, context);
+    let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+    let arms = parse_match_arms(parser, context);
+    SyntaxNode::match_(match_keyword, value, colon, arms)
+}
+
+fn parse_match_arms(
+    parser: &mut Parser,
+    context: &mut Context,
+) -> Vec<(SyntaxNode, Option<Token>)> {
+    let mut arms = Vec::new();
+    while parser.current_token().kind == TokenKind::Identifier {
+        let position = parser.position();
+        let variant = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+        let arrow = parser.match_token(TokenKind::TwoChars(['=', '>']), &mut context.diagnostics);
+        let body = parse_expression(parser, context);
+        let optional_comma = parser.try_match_token(TokenKind::SingleChar(','));
+        let is_last = optional_comma.is_none();
+        arms.push((SyntaxNode::match_arm(variant, arrow, body), optional_comma));
+        if let Some(consumed) = parser.ensure_consume(position) {
+            arms.push((SyntaxNode::error(consumed, true), None));
+        }
+        if is_last {
+            break;
+        }
+    }
+    arms
+}
+
+/// Parses a `match` statement. Its arms are written as `Variant:` followed
+/// by the statements, which are indented further than the variant.
+fn parse_match_statement(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let indentation = context.loaded_files[parser.current_token().location.file]
+        .column(parser.current_token().location.start);
+    let match_keyword = parser.match_token(TokenKind::MatchKeyword, &mut context.diagnostics);
+    let value = parse_expression(parser, context);
+    let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+    let column = |token: &Token, context: &Context| {
+        context.loaded_files[token.location.file].column(token.location.start)
+    };
+    let mut arms = Vec::new();
+    while parser.current_token().kind == TokenKind::Identifier
+        && column(parser.current_token(), context) > indentation
+    {
+        let arm_indentation = column(parser.current_token(), context);
+        let variant = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+        let arm_colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+        let mut body = Vec::new();
+        while !is_start_of_top_level_statement(parser.current_token().kind)
+            && column(parser.current_token(), context) > arm_indentation
+        {
+            let position = parser.position();
+            body.push(parse_statement(parser, context));
+            if let Some(consumed) = parser.ensure_consume(position) {
+                body.push(SyntaxNode::error(consumed, true));
+            }
+        }
+        arms.push(SyntaxNode::match_statement_arm(variant, arm_colon, body));
+    }
+    SyntaxNode::match_statement(match_keyword, value, colon, arms)
+}
+
+fn parse_if(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
+    let if_keyword = parser.match_token(TokenKind::IfKeyword, &mut context.diagnostics);
+    let optional_let_binding = match parser.try_match_token(TokenKind::LetKeyword) {
+        Some(let_keyword) => {
+            let name = parser.match_token(TokenKind::Identifier, &mut context.diagnostics);
+            let equals = parser.match_token(TokenKind::SingleChar('='), &mut context.diagnostics);
+            Some((let_keyword, name, equals))
+        }
+        None => None,
+    };
+    let condition = parse_expression(parser, context);
+    let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+    let body = parse_expression(parser, context);
+    let optional_else = match parser.try_match_token(TokenKind::ElseKeyword) {
+        Some(else_keyword) => {
+            let colon = parser.match_token(TokenKind::SingleChar(':'), &mut context.diagnostics);
+            let body = parse_expression(parser, context);
+            Some((else_keyword, colon, body))
+        }
+        None => None,
+    };
+    SyntaxNode::if_(
+        if_keyword,
+        optional_let_binding,
+        condition,
+        colon,
+        body,
+        optional_else,
+    )
+}
+
 fn parse_lambda(parser: &mut Parser, context: &mut Context) -> SyntaxNode {
-    let parameter = parse_parameter_node(parser, context);
+    let parameter = if parser.current_token().kind == TokenKind::Identifier {
+        parse_parameter(parser, context, false)
+    } else {
+        parse_parameter_node(parser, context, false)
+    };
     let arrow = parser.match_token(TokenKind::TwoChars(['=', '>']), &mut context.diagnostics);
     let body = parse_expression(parser, context);
     SyntaxNode::lambda(parameter, arrow, body)
@@ -1349,7 +2084,12 @@ fn is_start_of_top_level_statement(kind: TokenKind) -> bool {
             | TokenKind::Eof
             | TokenKind::ElementKeyword
             | TokenKind::TemplateKeyword
+            | TokenKind::LayoutKeyword
             | TokenKind::GlobalKeyword
+            | TokenKind::EnumKeyword
+            | TokenKind::SectionKeyword
+            | TokenKind::UseKeyword
+            | TokenKind::TokensKeyword
     )
 }
 
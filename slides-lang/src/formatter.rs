@@ -10,7 +10,7 @@ use crate::{
         self, DebugLang,
         evaluator::value::Value,
         lexer::{Token, TokenKind},
-        parser::{SyntaxNodeKind, debug_ast},
+        parser::{SyntaxNode, SyntaxNodeKind, debug_ast},
     },
 };
 
@@ -344,6 +344,18 @@ fn format_node<W: Write + fmt::Debug>(
         SyntaxNodeKind::ImportStatement(import_statement) => {
             format_import_statement(import_statement, formatter, context)
         }
+        SyntaxNodeKind::UseStatement(use_statement) => {
+            format_use_statement(use_statement, formatter, context)
+        }
+        SyntaxNodeKind::EnumStatement(enum_statement) => {
+            format_enum_statement(enum_statement, formatter, context)
+        }
+        SyntaxNodeKind::SectionStatement(section_statement) => {
+            format_section_statement(section_statement, formatter, context)
+        }
+        SyntaxNodeKind::RemoveStatement(remove_statement) => {
+            format_remove_statement(remove_statement, formatter, context)
+        }
         SyntaxNodeKind::ExpressionStatement(expression_statement) => {
             format_expression_statement(expression_statement, formatter, context)
         }
@@ -377,6 +389,10 @@ fn format_node<W: Write + fmt::Debug>(
         SyntaxNodeKind::FunctionCall(function_call) => {
             format_function_call(function_call, formatter, context)
         }
+        SyntaxNodeKind::Children(children) => format_children(children, formatter, context),
+        SyntaxNodeKind::NamedArgument(named_argument) => {
+            format_named_argument(named_argument, formatter, context)
+        }
         SyntaxNodeKind::TypedString(typed_string) => {
             format_typed_string(typed_string, formatter, context)
         }
@@ -398,6 +414,15 @@ fn format_node<W: Write + fmt::Debug>(
             format_parameter_block(parameter_block, formatter, context)
         }
         SyntaxNodeKind::Binary(binary) => format_binary(binary, formatter, context),
+        SyntaxNodeKind::Match(match_) => format_match(match_, formatter, context),
+        SyntaxNodeKind::If(if_) => format_if(if_, formatter, context),
+        SyntaxNodeKind::MatchArm(match_arm) => format_match_arm(match_arm, formatter, context),
+        SyntaxNodeKind::MatchStatement(match_statement) => {
+            format_match_statement(match_statement, formatter, context)
+        }
+        SyntaxNodeKind::MatchStatementArm(match_statement_arm) => {
+            format_match_statement_arm(match_statement_arm, formatter, context)
+        }
     }
 }
 
@@ -542,6 +567,198 @@ fn format_lambda<W: Write + fmt::Debug>(
     Ok(())
 }
 
+fn format_match<W: Write + fmt::Debug>(
+    match_: compiler::parser::Match,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> std::result::Result<(), std::io::Error> {
+    formatter.emit_token(
+        match_.match_keyword,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    format_node(*match_.value, formatter, context)?;
+    formatter.emit_token(match_.colon, &context.loaded_files, TokenConfig::default())?;
+    formatter.indent += 4;
+    for (arm, comma) in match_.arms {
+        formatter.ensure_indented_line()?;
+        format_node(arm, formatter, context)?;
+        if let Some(comma) = comma {
+            formatter.emit_token(comma, &context.loaded_files, TokenConfig::default())?;
+        }
+    }
+    formatter.indent -= 4;
+    Ok(())
+}
+
+fn format_if<W: Write + fmt::Debug>(
+    if_: compiler::parser::If,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> std::result::Result<(), std::io::Error> {
+    formatter.emit_token(
+        if_.if_keyword,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    if let Some((let_keyword, name, equals)) = if_.optional_let_binding {
+        formatter.emit_token(
+            let_keyword,
+            &context.loaded_files,
+            TokenConfig::TRAILING_SPACE,
+        )?;
+        formatter.emit_token(name, &context.loaded_files, TokenConfig::TRAILING_SPACE)?;
+        formatter.emit_token(equals, &context.loaded_files, TokenConfig::TRAILING_SPACE)?;
+    }
+    format_node(*if_.condition, formatter, context)?;
+    formatter.emit_token(
+        if_.colon,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    format_node(*if_.body, formatter, context)?;
+    if let Some((else_keyword, colon, body)) = if_.optional_else {
+        formatter.ensure_space()?;
+        formatter.emit_token(else_keyword, &context.loaded_files, TokenConfig::default())?;
+        formatter.emit_token(colon, &context.loaded_files, TokenConfig::TRAILING_SPACE)?;
+        format_node(*body, formatter, context)?;
+    }
+    Ok(())
+}
+
+fn format_match_arm<W: Write + fmt::Debug>(
+    match_arm: compiler::parser::MatchArm,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> std::result::Result<(), std::io::Error> {
+    formatter.emit_token(
+        match_arm.variant,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    formatter.emit_token(
+        match_arm.arrow,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    format_node(*match_arm.body, formatter, context)?;
+    Ok(())
+}
+
+fn format_match_statement<W: Write + fmt::Debug>(
+    match_statement: compiler::parser::MatchStatement,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> Result<()> {
+    formatter.emit_token(
+        match_statement.match_keyword,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    format_node(*match_statement.value, formatter, context)?;
+    formatter.emit_token(
+        match_statement.colon,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.ensure_new_line()?;
+    formatter.indent += 4;
+    for arm in match_statement.arms {
+        format_node(arm, formatter, context)?;
+    }
+    formatter.indent -= 4;
+    Ok(())
+}
+
+fn format_match_statement_arm<W: Write + fmt::Debug>(
+    match_statement_arm: compiler::parser::MatchStatementArm,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> Result<()> {
+    formatter.emit_token(
+        match_statement_arm.variant,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.emit_token(
+        match_statement_arm.colon,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.ensure_new_line()?;
+    formatter.indent += 4;
+    for statement in match_statement_arm.body {
+        format_node(statement, formatter, context)?;
+    }
+    formatter.indent -= 4;
+    Ok(())
+}
+
+fn format_enum_statement<W: Write + fmt::Debug>(
+    enum_statement: compiler::parser::EnumStatement,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> std::result::Result<(), std::io::Error> {
+    formatter.emit_token(
+        enum_statement.enum_keyword,
+        &context.loaded_files,
+        TokenConfig {
+            leading_blank_line: true,
+            trailing_space: true,
+            ..Default::default()
+        },
+    )?;
+    formatter.emit_token(
+        enum_statement.name,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.emit_token(
+        enum_statement.colon,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    for (variant, comma) in enum_statement.variants {
+        formatter.emit_token(variant, &context.loaded_files, TokenConfig::default())?;
+        if let Some(comma) = comma {
+            formatter.emit_token(comma, &context.loaded_files, TokenConfig::TRAILING_SPACE)?;
+        }
+    }
+    if let Some(semicolon) = enum_statement.optional_semicolon {
+        formatter.emit_token(semicolon, &context.loaded_files, TokenConfig::default())?;
+    }
+    formatter.ensure_new_line()?;
+    Ok(())
+}
+
+fn format_section_statement<W: Write + fmt::Debug>(
+    section_statement: compiler::parser::SectionStatement,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> std::result::Result<(), std::io::Error> {
+    formatter.emit_token(
+        section_statement.section_keyword,
+        &context.loaded_files,
+        TokenConfig {
+            leading_blank_line: true,
+            trailing_space: true,
+            ..Default::default()
+        },
+    )?;
+    formatter.emit_token(
+        section_statement.title,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.emit_token(
+        section_statement.colon,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.ensure_new_line()?;
+    Ok(())
+}
+
 fn format_import_statement<W: Write + fmt::Debug>(
     import_statement: compiler::parser::ImportStatement,
     formatter: &mut Formatter<W>,
@@ -561,22 +778,50 @@ fn format_import_statement<W: Write + fmt::Debug>(
     Ok(())
 }
 
-fn format_parameter<W: Write + fmt::Debug>(
-    parameter: compiler::parser::Parameter,
+fn format_use_statement<W: Write + fmt::Debug>(
+    use_statement: compiler::parser::UseStatement,
     formatter: &mut Formatter<W>,
     context: &mut Context,
 ) -> std::result::Result<(), std::io::Error> {
     formatter.emit_token(
-        parameter.identifier,
+        use_statement.use_keyword,
         &context.loaded_files,
-        TokenConfig::default(),
+        TokenConfig::TRAILING_SPACE,
     )?;
     formatter.emit_token(
-        parameter.colon,
+        use_statement.kind,
         &context.loaded_files,
         TokenConfig::TRAILING_SPACE,
     )?;
-    format_type_node(parameter.type_, formatter, context)?;
+    formatter.emit_token(
+        use_statement.name,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.emit_token(
+        use_statement.semicolon,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    Ok(())
+}
+
+fn format_parameter<W: Write + fmt::Debug>(
+    parameter: compiler::parser::Parameter,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> std::result::Result<(), std::io::Error> {
+    formatter.emit_token(
+        parameter.identifier,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    if let Some(colon) = parameter.optional_colon {
+        formatter.emit_token(colon, &context.loaded_files, TokenConfig::TRAILING_SPACE)?;
+    }
+    if let Some(type_) = parameter.optional_type {
+        format_type_node(type_, formatter, context)?;
+    }
     if let Some(equals) = parameter.optional_equals {
         formatter.ensure_space()?;
         formatter.emit_token(equals, &context.loaded_files, TokenConfig::TRAILING_SPACE)?;
@@ -690,12 +935,17 @@ fn format_expression_statement<W: Write + fmt::Debug>(
     context: &mut Context,
 ) -> Result<()> {
     formatter.ensure_indented_line()?;
-    format_node(*expression_statement.expression, formatter, context)?;
-    formatter.emit_token(
-        expression_statement.semicolon,
-        &context.loaded_files,
-        TokenConfig::default(),
-    )?;
+    let mut expression = *expression_statement.expression;
+    let children = take_children(&mut expression);
+    format_node(expression, formatter, context)?;
+    match children {
+        Some(children) => format_node(*children, formatter, context)?,
+        None => formatter.emit_token(
+            expression_statement.semicolon,
+            &context.loaded_files,
+            TokenConfig::default(),
+        )?,
+    }
     formatter.ensure_new_line()?;
     Ok(())
 }
@@ -819,20 +1069,52 @@ fn format_variable_declaration<W: Write + fmt::Debug>(
         TokenConfig::default(),
     )?;
     formatter.indent += 4;
-    let needed_space = variable_declaration.semicolon.location.end()
-        - variable_declaration.expression.location.start;
+    let mut expression = *variable_declaration.expression;
+    let children = take_children(&mut expression);
+    let end = children
+        .as_ref()
+        .map(|c| c.location.start + 1)
+        .unwrap_or(variable_declaration.semicolon.location.end());
+    let needed_space = end - expression.location.start;
     if formatter.available_space() < needed_space {
         formatter.ensure_indented_line()?;
     } else {
         formatter.ensure_space()?;
     }
-    format_node(*variable_declaration.expression, formatter, context)?;
+    format_node(expression, formatter, context)?;
+    formatter.indent -= 4;
+    match children {
+        Some(children) => format_node(*children, formatter, context)?,
+        None => formatter.emit_token(
+            variable_declaration.semicolon,
+            &context.loaded_files,
+            TokenConfig::default(),
+        )?,
+    }
+    formatter.ensure_new_line()?;
+    Ok(())
+}
+
+fn format_remove_statement<W: Write + fmt::Debug>(
+    remove_statement: compiler::parser::RemoveStatement,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> Result<()> {
     formatter.emit_token(
-        variable_declaration.semicolon,
+        remove_statement.remove_keyword,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    formatter.emit_token(
+        remove_statement.name,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.emit_token(
+        remove_statement.semicolon,
         &context.loaded_files,
         TokenConfig::default(),
     )?;
-    formatter.indent -= 4;
     formatter.ensure_new_line()?;
     Ok(())
 }
@@ -842,6 +1124,10 @@ fn format_type_node<W: Write + fmt::Debug>(
     formatter: &mut Formatter<W>,
     context: &mut Context,
 ) -> Result<()> {
+    if let Some((array, colon)) = type_.optional_array {
+        formatter.emit_token(array, &context.loaded_files, TokenConfig::default())?;
+        formatter.emit_token(colon, &context.loaded_files, TokenConfig::default())?;
+    }
     for (period, segment) in type_.path {
         if let Some(period) = period {
             formatter.emit_token(period, &context.loaded_files, TokenConfig::default())?;
@@ -877,6 +1163,24 @@ fn format_slide_statement<W: Write + fmt::Debug>(
         &context.loaded_files,
         TokenConfig::default(),
     )?;
+    if let Some((extends_keyword, base)) = slide_statement.optional_extends {
+        formatter.ensure_space()?;
+        formatter.emit_token(
+            extends_keyword,
+            &context.loaded_files,
+            TokenConfig::TRAILING_SPACE,
+        )?;
+        formatter.emit_token(base, &context.loaded_files, TokenConfig::default())?;
+    }
+    if let Some((uses_keyword, layout)) = slide_statement.optional_uses {
+        formatter.ensure_space()?;
+        formatter.emit_token(
+            uses_keyword,
+            &context.loaded_files,
+            TokenConfig::TRAILING_SPACE,
+        )?;
+        formatter.emit_token(layout, &context.loaded_files, TokenConfig::default())?;
+    }
     formatter.emit_token(
         slide_statement.colon,
         &context.loaded_files,
@@ -1027,6 +1331,53 @@ fn format_function_call<W: Write + fmt::Debug>(
     Ok(())
 }
 
+/// Children are formatted by the surrounding statement, since their body is
+/// indented relative to the statement and not to the function call.
+fn take_children(expression: &mut SyntaxNode) -> Option<Box<SyntaxNode>> {
+    match &mut expression.kind {
+        SyntaxNodeKind::FunctionCall(function_call) => function_call.optional_children.take(),
+        _ => None,
+    }
+}
+
+fn format_children<W: Write + fmt::Debug>(
+    children: compiler::parser::Children,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> Result<()> {
+    formatter.emit_token(
+        children.colon,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.ensure_new_line()?;
+    formatter.indent += 4;
+    for statement in children.body {
+        format_node(statement, formatter, context)?;
+    }
+    formatter.indent -= 4;
+    Ok(())
+}
+
+fn format_named_argument<W: Write + fmt::Debug>(
+    named_argument: compiler::parser::NamedArgument,
+    formatter: &mut Formatter<W>,
+    context: &mut Context,
+) -> Result<()> {
+    formatter.emit_token(
+        named_argument.name,
+        &context.loaded_files,
+        TokenConfig::default(),
+    )?;
+    formatter.emit_token(
+        named_argument.colon,
+        &context.loaded_files,
+        TokenConfig::TRAILING_SPACE,
+    )?;
+    format_node(*named_argument.value, formatter, context)?;
+    Ok(())
+}
+
 fn format_assignment_statement<W: Write + fmt::Debug>(
     assignment_statement: compiler::parser::AssignmentStatement,
     formatter: &mut Formatter<W>,
@@ -1040,12 +1391,17 @@ fn format_assignment_statement<W: Write + fmt::Debug>(
         &context.loaded_files,
         TokenConfig::TRAILING_SPACE,
     )?;
-    format_node(*assignment_statement.assignment, formatter, context)?;
-    formatter.emit_token(
-        assignment_statement.semicolon,
-        &context.loaded_files,
-        TokenConfig::default(),
-    )?;
+    let mut assignment = *assignment_statement.assignment;
+    let children = take_children(&mut assignment);
+    format_node(assignment, formatter, context)?;
+    match children {
+        Some(children) => format_node(*children, formatter, context)?,
+        None => formatter.emit_token(
+            assignment_statement.semicolon,
+            &context.loaded_files,
+            TokenConfig::default(),
+        )?,
+    }
     formatter.ensure_new_line()?;
     Ok(())
 }
@@ -1084,6 +1440,15 @@ fn format_styling_statement<W: std::io::Write + fmt::Debug>(
         &context.loaded_files,
         TokenConfig::default(),
     )?;
+    if let Some((extends_keyword, base)) = styling_statement.optional_extends {
+        formatter.ensure_space()?;
+        formatter.emit_token(
+            extends_keyword,
+            &context.loaded_files,
+            TokenConfig::TRAILING_SPACE,
+        )?;
+        formatter.emit_token(base, &context.loaded_files, TokenConfig::default())?;
+    }
     formatter.emit_token(
         styling_statement.colon,
         &context.loaded_files,
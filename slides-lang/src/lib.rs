@@ -36,6 +36,15 @@ impl Location {
         self.start + self.length
     }
 
+    /// Returns the `length` bytes starting `offset` bytes into this location.
+    pub(crate) fn slice(&self, offset: usize, length: usize) -> Self {
+        Self {
+            file: self.file,
+            start: self.start + offset,
+            length,
+        }
+    }
+
     pub const fn zero() -> Location {
         Self {
             file: FileId::ZERO,
@@ -83,6 +92,14 @@ impl File {
             Err(it) => it,
         }
     }
+
+    fn column(&self, start: usize) -> usize {
+        let line_start = match self.line_breaks.binary_search(&start) {
+            Ok(0) | Err(0) => 0,
+            Ok(it) | Err(it) => self.line_breaks[it - 1] + 1,
+        };
+        start - line_start
+    }
 }
 
 pub struct Files {
@@ -208,6 +225,9 @@ pub struct Context {
     type_interner: TypeInterner,
     debug: DebugLang,
     modules: Modules,
+    /// The theme selected when building the presentation. It replaces the
+    /// theme used by the presentation itself.
+    theme: Option<String>,
 }
 
 impl Context {
@@ -220,6 +240,7 @@ impl Context {
             type_interner: TypeInterner::new(),
             debug: DebugLang::default(),
             modules: Modules::new(),
+            theme: None,
         }
     }
 
@@ -13,6 +13,13 @@ enum Command {
         output: PathBuf,
         #[clap(short, long = "dbg", default_value = "")]
         debug: DebugLang,
+        /// Theme from slides-themes/ next to the presentation replacing its theme.
+        #[clap(long)]
+        theme: Option<String>,
+        /// Directory with cached google fonts, which are copied into the
+        /// output instead of being loaded from the internet.
+        #[clap(long)]
+        font_cache: Option<PathBuf>,
     },
     Format {
         file: PathBuf,
@@ -27,6 +34,13 @@ enum Command {
         output: PathBuf,
         #[clap(short, long = "dbg", default_value = "")]
         debug: DebugLang,
+        /// Theme from slides-themes/ next to the presentation replacing its theme.
+        #[clap(long)]
+        theme: Option<String>,
+        /// Directory with cached google fonts, which are copied into the
+        /// output instead of being loaded from the internet.
+        #[clap(long)]
+        font_cache: Option<PathBuf>,
     },
 }
 
@@ -37,8 +51,10 @@ fn main() -> anyhow::Result<()> {
             file,
             output,
             debug,
+            theme,
+            font_cache,
         } => {
-            slides_lang::compiler::compile_project(file, output, debug)?;
+            slides_lang::compiler::compile_project(file, output, debug, theme, font_cache)?;
         }
         Command::Format { file, dry, debug } => {
             slides_lang::formatter::format_file(file, dry, debug)?;
@@ -47,17 +63,31 @@ fn main() -> anyhow::Result<()> {
             file,
             output,
             debug,
+            theme,
+            font_cache,
         } => {
-            watch(file, output, debug)?;
+            watch(file, output, debug, theme, font_cache)?;
         }
     }
     Ok(())
 }
 
-fn watch(file: PathBuf, output: PathBuf, debug: DebugLang) -> Result<(), anyhow::Error> {
+fn watch(
+    file: PathBuf,
+    output: PathBuf,
+    debug: DebugLang,
+    theme: Option<String>,
+    font_cache: Option<PathBuf>,
+) -> Result<(), anyhow::Error> {
     let (tx, rx) = std::sync::mpsc::channel::<notify::Result<notify::Event>>();
     let mut watcher = notify::recommended_watcher(tx)?;
-    match slides_lang::compiler::compile_project(&file, &output, debug) {
+    match slides_lang::compiler::compile_project(
+        &file,
+        &output,
+        debug,
+        theme.clone(),
+        font_cache.clone(),
+    ) {
         Ok(result) => {
             dbg!(&result);
             for file in result.used_files {
@@ -70,7 +100,13 @@ fn watch(file: PathBuf, output: PathBuf, debug: DebugLang) -> Result<(), anyhow:
     }
     for event in rx {
         let _event = event?;
-        match slides_lang::compiler::compile_project(&file, &output, debug) {
+        match slides_lang::compiler::compile_project(
+            &file,
+            &output,
+            debug,
+            theme.clone(),
+            font_cache.clone(),
+        ) {
             Ok(result) => {
                 for file in result.used_files {
                     watcher.watch(&file, notify::RecursiveMode::NonRecursive)?;
@@ -23,6 +23,16 @@ function init_navigation() {
     }
     slides[activeSlide].classList.add("active");
     currentStepCount = slides[activeSlide].dataset.stepCount;
+    // Links inside the presentation (e.g. from toc()) only change the hash.
+    window.addEventListener("hashchange", () => {
+        var slide_id = window.location.hash.slice(1);
+        for (let i = 0; i < slides.length; i++) {
+            if (slides[i].id == slide_id && i != activeSlide) {
+                move_to_slide(i);
+                break;
+            }
+        }
+    });
 }
 
 function getElementById(id) {
@@ -1,6 +1,5 @@
 use std::{
     cell::RefCell,
-    collections::HashSet,
     fmt::{Debug, Display},
     sync::{Arc, RwLock, atomic::AtomicUsize},
 };
@@ -8,8 +7,8 @@ use std::{
 use enum_dispatch::enum_dispatch;
 
 use crate::{
-    BaseElementStyling, Result, StyleUnit, StylingReference, ToCssLayout, animations::Animation,
-    output::PresentationEmitter,
+    BaseElementStyling, FontReferences, Result, StyleUnit, StylingReference, ToCssLayout,
+    animations::Animation, output::PresentationEmitter,
 };
 
 mod image;
@@ -58,7 +57,7 @@ pub trait WebRenderable {
         emitter: &mut PresentationEmitter<W>,
         ctx: WebRenderableContext,
     ) -> Result<()>;
-    fn collect_google_font_references(&self, _: &mut HashSet<String>) -> Result<()> {
+    fn collect_font_references(&self, _: &mut FontReferences) -> Result<()> {
         Ok(())
     }
     fn set_parent(&mut self, parent: ElementId);
@@ -124,8 +123,8 @@ impl<T: WebRenderable + Clone> WebRenderable for Arc<RefCell<T>> {
         // self.borrow_mut().element_styling_mut()
     }
 
-    fn collect_google_font_references(&self, fonts: &mut HashSet<String>) -> Result<()> {
-        self.borrow().collect_google_font_references(fonts)
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()> {
+        self.borrow().collect_font_references(fonts)
     }
 
     fn set_z_index(&mut self, z_index: usize) {
@@ -184,8 +183,8 @@ impl<T: WebRenderable + Clone + Debug> WebRenderable for Arc<RwLock<T>> {
         // self.write().unwrap().element_styling_mut()
     }
 
-    fn collect_google_font_references(&self, fonts: &mut HashSet<String>) -> Result<()> {
-        self.read().unwrap().collect_google_font_references(fonts)
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()> {
+        self.read().unwrap().collect_font_references(fonts)
     }
 
     fn set_z_index(&mut self, z_index: usize) {
@@ -284,11 +283,11 @@ impl ElementRefMut {
     }
 
     pub fn set_width(&mut self, value: StyleUnit) {
-        self.apply_to_base_element_styling(|base| base.set_width(value));
+        self.apply_to_base_element_styling(|base| base.set_width(value.clone()));
     }
 
     pub fn set_height(&mut self, value: StyleUnit) {
-        self.apply_to_base_element_styling(|base| base.set_height(value));
+        self.apply_to_base_element_styling(|base| base.set_height(value.clone()));
     }
 
     pub fn set_z_index(&mut self, value: usize) {
@@ -300,19 +299,19 @@ impl ElementRefMut {
     }
 
     pub fn set_margin(&mut self, value: crate::Thickness) {
-        self.apply_to_base_element_styling(|base| base.set_margin(value));
+        self.apply_to_base_element_styling(|base| base.set_margin(value.clone()));
     }
 
     pub fn set_padding(&mut self, value: crate::Thickness) {
-        self.apply_to_base_element_styling(|base| base.set_padding(value));
+        self.apply_to_base_element_styling(|base| base.set_padding(value.clone()));
     }
 
     pub fn set_background(&mut self, value: crate::Background) {
-        self.apply_to_base_element_styling(|base| base.set_background(value));
+        self.apply_to_base_element_styling(|base| base.set_background(value.clone()));
     }
 
     pub fn set_filter(&mut self, value: crate::Filter) {
-        self.apply_to_base_element_styling(|base| base.set_filter(value));
+        self.apply_to_base_element_styling(|base| base.set_filter(value.clone()));
     }
 
     pub fn set_animations(&mut self, value: Vec<Animation>) {
@@ -327,7 +326,31 @@ impl ElementRefMut {
         }
     }
 
-    pub fn set_rotation(&mut self, degree: i64) {
-        self.apply_to_base_element_styling(|base| base.set_rotation(degree));
+    pub fn set_rotation(&mut self, angle: crate::Angle) {
+        self.apply_to_base_element_styling(|base| base.set_rotation(angle));
+    }
+
+    pub fn set_border(&mut self, value: crate::Border) {
+        self.apply_to_base_element_styling(|base| base.set_border(value.clone()));
+    }
+
+    pub fn set_border_radius(&mut self, value: crate::StyleUnit) {
+        self.apply_to_base_element_styling(|base| base.set_border_radius(value.clone()));
+    }
+
+    pub fn set_box_shadow(&mut self, value: crate::BoxShadow) {
+        self.apply_to_base_element_styling(|base| base.set_box_shadow(value.clone()));
+    }
+
+    pub fn set_opacity(&mut self, value: f64) {
+        self.apply_to_base_element_styling(|base| base.set_opacity(value));
+    }
+
+    pub fn set_transform(&mut self, value: crate::Transform) {
+        self.apply_to_base_element_styling(|base| base.set_transform(value.clone()));
+    }
+
+    pub fn set_transform_origin(&mut self, value: crate::Position) {
+        self.apply_to_base_element_styling(|base| base.set_transform_origin(value.clone()));
     }
 }
@@ -50,12 +50,25 @@ impl CustomElement {
         self.stylings.push(reference);
     }
 
-    pub fn with_elements(mut self, mut children: Vec<Element>) -> CustomElement {
-        children.iter_mut().for_each(|c| c.set_parent(self.id));
-        self.children = children;
+    pub fn with_elements(mut self, children: Vec<Element>) -> CustomElement {
+        self.set_elements(children);
         self
     }
 
+    /// Replaces all children, e.g. after the element was evaluated again.
+    pub fn set_elements(&mut self, mut children: Vec<Element>) {
+        let namespace = format!("{}-{}", self.namespace, self.name);
+        children.iter_mut().for_each(|c| {
+            c.set_parent(self.id);
+            c.set_namespace(namespace.clone());
+        });
+        self.children = children;
+    }
+
+    pub fn children(&self) -> &[Element] {
+        &self.children
+    }
+
     pub fn name(&self) -> String {
         if self.name.is_empty() {
             format!("{}-{}", self.styling.class_name(), self.id)
@@ -88,6 +101,7 @@ impl WebRenderable for CustomElement {
         self.animations.apply_to_styling(&mut self.styling);
         self.styling
             .to_css_rule(ctx.layout.clone(), &format!("#{id}"), emitter.raw_css())?;
+        self.styling.add_files(emitter)?;
         writeln!(
             emitter.raw_html(),
             "<div id=\"{id}\" class=\"custom-element {} {classes} {classes_animations}\" data-element-id=\"{}\">",
@@ -143,12 +157,9 @@ impl WebRenderable for CustomElement {
         self.styling.base()
     }
 
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut std::collections::HashSet<String>,
-    ) -> crate::Result<()> {
+    fn collect_font_references(&self, fonts: &mut crate::FontReferences) -> crate::Result<()> {
         for child in &self.children {
-            child.collect_google_font_references(fonts)?;
+            child.collect_font_references(fonts)?;
         }
         Ok(())
     }
@@ -73,6 +73,7 @@ impl WebRenderable for Flex {
             .join(" ");
         self.styling
             .to_css_rule(ctx.layout.clone(), &format!("#{id}"), emitter.raw_css())?;
+        self.styling.add_files(emitter)?;
         writeln!(
             emitter.raw_html(),
             "<div id=\"{id}\" class=\"flex {classes}{classes_animations}\" data-element-id=\"{}\">",
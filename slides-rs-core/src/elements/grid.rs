@@ -54,6 +54,10 @@ impl Grid {
         }
     }
 
+    pub fn styling_mut(&mut self) -> &mut ElementStyling<GridStyling> {
+        &mut self.styling
+    }
+
     pub fn add_styling(&mut self, styling: StylingReference) {
         self.stylings.push(styling);
     }
@@ -95,6 +99,7 @@ impl WebRenderable for Grid {
         self.animations.apply_to_styling(&mut self.styling);
         self.styling
             .to_css_rule(ctx.layout.clone(), &format!("#{id}"), emitter.raw_css())?;
+        self.styling.add_files(emitter)?;
         writeln!(
             emitter.raw_html(),
             "<div id=\"{id}\" class=\"grid {classes} {classes_animations}\" data-element-id=\"{}\">",
@@ -19,7 +19,7 @@ pub struct Image {
     pub animations: Animations,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ImageSource {
     Path(PathBuf),
 }
@@ -29,7 +29,10 @@ impl ImageSource {
         Self::Path(path.into())
     }
 
-    fn add_files<W: std::io::Write>(&self, emitter: &mut PresentationEmitter<W>) -> Result<()> {
+    pub(crate) fn add_files<W: std::io::Write>(
+        &self,
+        emitter: &mut PresentationEmitter<W>,
+    ) -> Result<()> {
         match self {
             ImageSource::Path(path_buf) => emitter.add_file(path_buf)?,
         }
@@ -104,6 +107,7 @@ impl WebRenderable for Image {
         self.animations.apply_to_styling(&mut self.styling);
         self.styling
             .to_css_rule(ctx.layout, &format!("#{id}"), emitter.raw_css())?;
+        self.styling.add_files(emitter)?;
         self.source.add_files(emitter)?;
         writeln!(
             emitter.raw_html(),
@@ -49,11 +49,8 @@ pub struct Label {
 }
 
 impl WebRenderable for Label {
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
-        self.styling.collect_google_font_references(fonts)
+    fn collect_font_references(&self, fonts: &mut crate::FontReferences) -> Result<()> {
+        self.styling.collect_font_references(fonts)
     }
 
     fn output_to_html<W: std::io::Write>(
@@ -74,6 +71,7 @@ impl WebRenderable for Label {
         self.animations.apply_to_styling(&mut self.styling);
         self.styling
             .to_css_rule(ctx.layout, &format!("#{id}"), emitter.raw_css())?;
+        self.styling.add_files(emitter)?;
         writeln!(
             emitter.raw_html(),
             "<div id=\"{id}\" class=\"label {classes} {classes_animations}\" data-element-id=\"{}\">",
@@ -153,6 +151,10 @@ impl Label {
         self.stylings.push(reference);
     }
 
+    pub fn text(&self) -> &str {
+        &self.text.text
+    }
+
     pub fn name(&self) -> String {
         if self.name.is_empty() {
             format!("{}-{}", self.styling.class_name(), self.id)
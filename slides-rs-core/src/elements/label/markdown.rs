@@ -25,8 +25,8 @@ pub const SLIDE_MARKDOWN_PARSE_OPTIONS: ParseOptions = ParseOptions {
         html_flow: false,
         html_text: false,
         label_start_image: false,
-        label_start_link: false,
-        label_end: false,
+        label_start_link: true,
+        label_end: true,
         list_item: true,
         math_flow: true,
         math_text: true,
@@ -55,7 +55,7 @@ pub fn render_markdown<W: std::io::Write>(w: &mut W, node: mdast::Node) -> std::
         mdast::Node::Toml(toml) => todo!(),
         mdast::Node::Yaml(yaml) => todo!(),
         mdast::Node::Break(_) => todo!(),
-        mdast::Node::InlineCode(inline_code) => todo!(),
+        mdast::Node::InlineCode(inline_code) => render_inline_code(w, inline_code),
         mdast::Node::InlineMath(inline_math) => todo!(),
         mdast::Node::Delete(delete) => todo!(),
         mdast::Node::Emphasis(emphasis) => render_emphasis(w, emphasis),
@@ -65,9 +65,9 @@ pub fn render_markdown<W: std::io::Write>(w: &mut W, node: mdast::Node) -> std::
         mdast::Node::Image(image) => todo!(),
         mdast::Node::ImageReference(image_reference) => todo!(),
         mdast::Node::MdxJsxTextElement(mdx_jsx_text_element) => todo!(),
-        mdast::Node::Link(link) => todo!(),
+        mdast::Node::Link(link) => render_link(w, link),
         mdast::Node::LinkReference(link_reference) => todo!(),
-        mdast::Node::Strong(strong) => todo!(),
+        mdast::Node::Strong(strong) => render_strong(w, strong),
         mdast::Node::Text(text) => render_text(w, text),
         mdast::Node::Code(code) => todo!(),
         mdast::Node::Math(math) => todo!(),
@@ -95,6 +95,38 @@ fn render_emphasis<W: std::io::Write>(
     Ok(())
 }
 
+fn render_strong<W: std::io::Write>(
+    w: &mut W,
+    strong: mdast::Strong,
+) -> Result<(), std::io::Error> {
+    write!(w, "<strong>")?;
+    for child in strong.children {
+        render_markdown(w, child)?;
+    }
+    write!(w, "</strong>")?;
+    Ok(())
+}
+
+fn render_inline_code<W: std::io::Write>(
+    w: &mut W,
+    inline_code: mdast::InlineCode,
+) -> Result<(), std::io::Error> {
+    // Code often contains `<` or `&`, which must not be read as html.
+    let code = html_escape::encode_text(&inline_code.value);
+    write!(w, "<code>{code}</code>")
+}
+
+fn render_link<W: std::io::Write>(w: &mut W, link: mdast::Link) -> Result<(), std::io::Error> {
+    // Links are written by users, so their urls must not end the attribute.
+    let url = html_escape::encode_double_quoted_attribute(&link.url);
+    write!(w, "<a href=\"{url}\">")?;
+    for child in link.children {
+        render_markdown(w, child)?;
+    }
+    write!(w, "</a>")?;
+    Ok(())
+}
+
 fn render_list_item<W: std::io::Write>(
     w: &mut W,
     list_item: mdast::ListItem,
@@ -1,11 +1,12 @@
 use std::{
     fmt::Display,
     num::ParseFloatError,
-    ops::{Add, Mul, Sub},
+    ops::{Add, Div, Mul, Sub},
     str::FromStr,
+    sync::Arc,
 };
 
-use crate::SlidesEnum;
+use crate::{SlidesEnum, TokenName};
 
 // #[derive(Debug, Clone, Copy)]
 // pub struct Positioning {
@@ -138,7 +139,9 @@ use crate::SlidesEnum;
 //     }
 // }
 
-#[derive(Debug, Clone, Copy, strum::VariantNames, strum::EnumString, Default)]
+#[derive(
+    Debug, Clone, Copy, strum::VariantNames, strum::EnumString, strum::IntoStaticStr, Default,
+)]
 pub enum VerticalAlignment {
     #[default]
     Unset,
@@ -150,7 +153,9 @@ pub enum VerticalAlignment {
 
 impl SlidesEnum for VerticalAlignment {}
 
-#[derive(Debug, Clone, Copy, strum::VariantNames, strum::EnumString, Default)]
+#[derive(
+    Debug, Clone, Copy, strum::VariantNames, strum::EnumString, strum::IntoStaticStr, Default,
+)]
 pub enum HorizontalAlignment {
     #[default]
     Unset,
@@ -162,7 +167,7 @@ pub enum HorizontalAlignment {
 
 impl SlidesEnum for HorizontalAlignment {}
 
-#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct Thickness {
     pub left: StyleUnit,
     pub top: StyleUnit,
@@ -174,12 +179,26 @@ impl Thickness {
     pub fn all(value: impl Into<StyleUnit>) -> Thickness {
         let value = value.into();
         Self {
-            left: value,
-            top: value,
-            right: value,
+            left: value.clone(),
+            top: value.clone(),
+            right: value.clone(),
             bottom: value,
         }
     }
+
+    /// Overrides every side, which is specified in `other`.
+    pub fn merge(&mut self, other: &Thickness) {
+        for (side, other) in [
+            (&mut self.left, &other.left),
+            (&mut self.top, &other.top),
+            (&mut self.right, &other.right),
+            (&mut self.bottom, &other.bottom),
+        ] {
+            if *other != StyleUnit::Unspecified {
+                *side = other.clone();
+            }
+        }
+    }
 }
 
 impl Display for Thickness {
@@ -192,100 +211,175 @@ impl Display for Thickness {
     }
 }
 
+/// The units, which can be combined linearly in a [`CalcData`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LinearUnit {
+    Pixel,
+    Point,
+    Percent,
+    SlideWidth,
+    SlideHeight,
+    Em,
+    Rem,
+    ViewportWidth,
+    ViewportHeight,
+    Centimeter,
+    Millimeter,
+    Inch,
+}
+
+impl LinearUnit {
+    const ALL: [LinearUnit; 12] = [
+        LinearUnit::Pixel,
+        LinearUnit::Point,
+        LinearUnit::Percent,
+        LinearUnit::SlideWidth,
+        LinearUnit::SlideHeight,
+        LinearUnit::Em,
+        LinearUnit::Rem,
+        LinearUnit::ViewportWidth,
+        LinearUnit::ViewportHeight,
+        LinearUnit::Centimeter,
+        LinearUnit::Millimeter,
+        LinearUnit::Inch,
+    ];
+
+    fn with_value(self, value: f64) -> StyleUnit {
+        match self {
+            LinearUnit::Pixel => StyleUnit::Pixel(value),
+            LinearUnit::Point => StyleUnit::Point(value),
+            LinearUnit::Percent => StyleUnit::Percent(value),
+            LinearUnit::SlideWidth => StyleUnit::SlideWidthRatio(value),
+            LinearUnit::SlideHeight => StyleUnit::SlideHeightRatio(value),
+            LinearUnit::Em => StyleUnit::Em(value),
+            LinearUnit::Rem => StyleUnit::Rem(value),
+            LinearUnit::ViewportWidth => StyleUnit::ViewportWidth(value),
+            LinearUnit::ViewportHeight => StyleUnit::ViewportHeight(value),
+            LinearUnit::Centimeter => StyleUnit::Centimeter(value),
+            LinearUnit::Millimeter => StyleUnit::Millimeter(value),
+            LinearUnit::Inch => StyleUnit::Inch(value),
+        }
+    }
+}
+
+/// A linear combination of units, which is emitted as `calc()`.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct CalcData {
-    pixel: f64,
-    percent: f64,
-    point: f64,
-    slide_width: f64,
-    slide_height: f64,
+    values: [f64; LinearUnit::ALL.len()],
 }
+
 impl CalcData {
-    fn add_pixel(mut self, px: f64) -> Self {
-        self.pixel += px;
-        self
+    /// Unspecified units count as zero. Returns `None` for units, which are not
+    /// linear, like tokens or the maximum of two units.
+    fn from_unit(unit: &StyleUnit) -> Option<Self> {
+        let mut result = Self::default();
+        match unit {
+            StyleUnit::Unspecified => {}
+            StyleUnit::Calc(calc_data) => result = *calc_data,
+            unit => {
+                let (unit, value) = unit.as_linear()?;
+                result.values[unit as usize] = value;
+            }
+        }
+        Some(result)
     }
 
-    fn add_percent(mut self, percent: f64) -> CalcData {
-        self.percent += percent;
+    fn add(mut self, other: CalcData) -> CalcData {
+        for (value, other) in self.values.iter_mut().zip(other.values) {
+            *value += other;
+        }
         self
     }
 
-    fn from_units(a: StyleUnit, b: StyleUnit) -> CalcData {
-        CalcData::from_unit(a).apply(b)
+    fn scale(mut self, factor: f64) -> CalcData {
+        for value in &mut self.values {
+            *value *= factor;
+        }
+        self
     }
 
-    fn from_unit(a: StyleUnit) -> Self {
-        match a {
-            StyleUnit::Unspecified => Self::default(),
-            StyleUnit::Pixel(pixel) => Self {
-                pixel,
-                ..Self::default()
-            },
-            StyleUnit::Point(point) => Self {
-                point,
-                ..Self::default()
-            },
-            StyleUnit::Percent(percent) => Self {
-                percent,
-                ..Self::default()
-            },
-            StyleUnit::SlideWidthRatio(slide_width) => Self {
-                slide_width,
-                ..Self::default()
-            },
-            StyleUnit::SlideHeightRatio(slide_height) => Self {
-                slide_height,
-                ..Self::default()
-            },
-            StyleUnit::Calc(calc_data) => calc_data,
-        }
+    fn terms(&self) -> impl Iterator<Item = (LinearUnit, f64)> {
+        LinearUnit::ALL
+            .into_iter()
+            .zip(self.values)
+            .filter(|(_, value)| *value != 0.0)
     }
 
-    fn apply(mut self, b: StyleUnit) -> CalcData {
-        match b {
-            StyleUnit::Unspecified => {}
-            StyleUnit::Pixel(pixel) => self.pixel += pixel,
-            StyleUnit::Point(point) => self.point += point,
-            StyleUnit::Percent(percent) => self.percent += percent,
-            StyleUnit::SlideWidthRatio(slide_width) => self.slide_width += slide_width,
-            StyleUnit::SlideHeightRatio(slide_height) => self.slide_height += slide_height,
-            StyleUnit::Calc(_calc_data) => todo!(),
+    /// Uses a single unit instead of `calc()` where possible.
+    fn simplify(self) -> StyleUnit {
+        let mut terms = self.terms();
+        match (terms.next(), terms.next()) {
+            (None, _) => StyleUnit::Pixel(0.0),
+            (Some((unit, value)), None) => unit.with_value(value),
+            (Some(_), Some(_)) => StyleUnit::Calc(self),
         }
-        self
     }
 }
 
 impl Display for CalcData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "calc(")?;
-        if self.pixel != 0.0 {
-            write!(f, "+ {:}px", self.pixel)?;
-        }
-        if self.percent != 0.0 {
-            write!(f, "+ {:}%", self.percent)?;
-        }
-        if self.point != 0.0 {
-            write!(f, "+ {:}pt", self.point)?;
+        let mut is_first = true;
+        for (unit, value) in self.terms() {
+            let term = match unit {
+                LinearUnit::SlideWidth => format!("{} * var(--slide-width)", value.abs()),
+                LinearUnit::SlideHeight => format!("{} * var(--slide-height)", value.abs()),
+                unit => unit.with_value(value.abs()).to_string(),
+            };
+            match (is_first, value < 0.0) {
+                (true, false) => write!(f, "{term}")?,
+                (true, true) => write!(f, "-{term}")?,
+                (false, false) => write!(f, " + {term}")?,
+                (false, true) => write!(f, " - {term}")?,
+            }
+            is_first = false;
         }
-        if self.slide_width != 0.0 {
-            write!(f, "+ ({:} * var(--slide-width))", self.slide_width)?;
+        if is_first {
+            write!(f, "0px")?;
         }
-        if self.slide_height != 0.0 {
-            write!(f, "+ ({:} * var(--slide-height))", self.slide_height)?;
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CalcNode {
+    Sum(StyleUnit, StyleUnit),
+    Product(StyleUnit, f64),
+    Max(StyleUnit, StyleUnit),
+    Min(StyleUnit, StyleUnit),
+    Clamp(StyleUnit, StyleUnit, StyleUnit),
+}
+
+/// A style unit, which cannot be expressed as a linear combination of units,
+/// like the maximum of two units or a sum containing tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalcExpression(Arc<CalcNode>);
+
+impl CalcExpression {
+    fn new(node: CalcNode) -> Self {
+        Self(Arc::new(node))
+    }
+}
+
+impl Display for CalcExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self.0 {
+            CalcNode::Sum(a, b) => write!(f, "calc({a} + {b})"),
+            CalcNode::Product(a, factor) => write!(f, "calc({a} * {factor})"),
+            CalcNode::Max(a, b) => write!(f, "max({a}, {b})"),
+            CalcNode::Min(a, b) => write!(f, "min({a}, {b})"),
+            CalcNode::Clamp(min, value, max) => write!(f, "clamp({min}, {value}, {max})"),
         }
-        write!(f, ")")?;
-        Ok(())
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Position {
     pub x: StyleUnit,
     pub y: StyleUnit,
 }
 
-#[derive(Debug, strum::Display, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, strum::Display, Clone, PartialEq, Default)]
 pub enum StyleUnit {
     #[strum(to_string = "unset")]
     #[default]
@@ -300,95 +394,111 @@ pub enum StyleUnit {
     SlideWidthRatio(f64),
     #[strum(to_string = "calc({0} * var(--slide-height))")]
     SlideHeightRatio(f64),
+    #[strum(to_string = "{0}em")]
+    Em(f64),
+    #[strum(to_string = "{0}rem")]
+    Rem(f64),
+    #[strum(to_string = "{0}vw")]
+    ViewportWidth(f64),
+    #[strum(to_string = "{0}vh")]
+    ViewportHeight(f64),
+    #[strum(to_string = "{0}cm")]
+    Centimeter(f64),
+    #[strum(to_string = "{0}mm")]
+    Millimeter(f64),
+    #[strum(to_string = "{0}in")]
+    Inch(f64),
     #[strum(to_string = "{0}")]
     Calc(CalcData),
+    #[strum(to_string = "{0}")]
+    Token(TokenName),
+    #[strum(to_string = "{0}")]
+    Expression(CalcExpression),
 }
 
+/// A style unit used as a font size. The language accepts plain numbers for
+/// it, which are multiples of the default font size.
+pub type FontSize = StyleUnit;
+
 impl StyleUnit {
-    fn add_pixel(&self, px: f64) -> StyleUnit {
-        match self {
-            StyleUnit::Unspecified => StyleUnit::Pixel(px),
-            StyleUnit::Pixel(spx) => StyleUnit::Pixel(spx + px),
-            StyleUnit::Point(_pt) => todo!(),
-            StyleUnit::Percent(_percent) => todo!(),
-            StyleUnit::SlideWidthRatio(_) => todo!(),
-            StyleUnit::SlideHeightRatio(_) => todo!(),
-            StyleUnit::Calc(calc_data) => StyleUnit::Calc(calc_data.add_pixel(px)),
-        }
+    /// The unit and the value of units, which can be part of a [`CalcData`].
+    fn as_linear(&self) -> Option<(LinearUnit, f64)> {
+        Some(match *self {
+            StyleUnit::Pixel(it) => (LinearUnit::Pixel, it),
+            StyleUnit::Point(it) => (LinearUnit::Point, it),
+            StyleUnit::Percent(it) => (LinearUnit::Percent, it),
+            StyleUnit::SlideWidthRatio(it) => (LinearUnit::SlideWidth, it),
+            StyleUnit::SlideHeightRatio(it) => (LinearUnit::SlideHeight, it),
+            StyleUnit::Em(it) => (LinearUnit::Em, it),
+            StyleUnit::Rem(it) => (LinearUnit::Rem, it),
+            StyleUnit::ViewportWidth(it) => (LinearUnit::ViewportWidth, it),
+            StyleUnit::ViewportHeight(it) => (LinearUnit::ViewportHeight, it),
+            StyleUnit::Centimeter(it) => (LinearUnit::Centimeter, it),
+            StyleUnit::Millimeter(it) => (LinearUnit::Millimeter, it),
+            StyleUnit::Inch(it) => (LinearUnit::Inch, it),
+            StyleUnit::Unspecified
+            | StyleUnit::Calc(_)
+            | StyleUnit::Token(_)
+            | StyleUnit::Expression(_) => return None,
+        })
     }
 
-    fn add_percent(&self, percent: f64) -> StyleUnit {
-        match self {
-            StyleUnit::Unspecified => StyleUnit::Percent(percent),
-            StyleUnit::Pixel(_) => todo!(),
-            StyleUnit::Point(_pt) => todo!(),
-            StyleUnit::Percent(spercent) => Self::Percent(*spercent + percent),
-            StyleUnit::SlideWidthRatio(_) => todo!(),
-            StyleUnit::SlideHeightRatio(_) => todo!(),
-            StyleUnit::Calc(calc_data) => StyleUnit::Calc(calc_data.add_percent(percent)),
+    /// The value of both units, if they use the same simple unit.
+    fn same_unit(&self, other: &Self) -> Option<(f64, f64, LinearUnit)> {
+        match (self.as_linear(), other.as_linear()) {
+            (Some((unit, a)), Some((other, b))) if unit == other => Some((a, b, unit)),
+            _ => None,
         }
     }
 
     pub fn max(&self, other: Self) -> Self {
         match (self, &other) {
-            (StyleUnit::Unspecified, max) => *max,
-            (max, StyleUnit::Unspecified) => *max,
-            (StyleUnit::Pixel(a), StyleUnit::Pixel(b)) => StyleUnit::Pixel(a.max(*b)),
-            (StyleUnit::Point(a), StyleUnit::Point(b)) => StyleUnit::Point(a.max(*b)),
-            (StyleUnit::Percent(a), StyleUnit::Percent(b)) => StyleUnit::Percent(a.max(*b)),
-            _ => todo!(),
+            (StyleUnit::Unspecified, max) => max.clone(),
+            (max, StyleUnit::Unspecified) => max.clone(),
+            (a, b) => match a.same_unit(b) {
+                Some((a, b, unit)) => unit.with_value(a.max(b)),
+                None if a == b => a.clone(),
+                None => {
+                    StyleUnit::Expression(CalcExpression::new(CalcNode::Max(a.clone(), b.clone())))
+                }
+            },
         }
     }
 
     pub fn min(&self, other: Self) -> Self {
         match (self, &other) {
-            (StyleUnit::Unspecified, min) => *min,
-            (min, StyleUnit::Unspecified) => *min,
-            (StyleUnit::Pixel(a), StyleUnit::Pixel(b)) => StyleUnit::Pixel(a.min(*b)),
-            (StyleUnit::Point(a), StyleUnit::Point(b)) => StyleUnit::Point(a.min(*b)),
-            (StyleUnit::Percent(a), StyleUnit::Percent(b)) => StyleUnit::Percent(a.min(*b)),
-            _ => todo!(),
+            (StyleUnit::Unspecified, min) => min.clone(),
+            (min, StyleUnit::Unspecified) => min.clone(),
+            (a, b) => match a.same_unit(b) {
+                Some((a, b, unit)) => unit.with_value(a.min(b)),
+                None if a == b => a.clone(),
+                None => {
+                    StyleUnit::Expression(CalcExpression::new(CalcNode::Min(a.clone(), b.clone())))
+                }
+            },
         }
     }
 
-    pub fn or_zero(&self) -> StyleUnit {
-        match self {
-            Self::Unspecified => StyleUnit::Pixel(0.0),
-            normal => *normal,
+    /// Restricts this unit to be between `min` and `max`. Unspecified bounds
+    /// are ignored.
+    pub fn clamp(&self, min: Self, max: Self) -> Self {
+        if [self, &min, &max].contains(&&StyleUnit::Unspecified) {
+            return self.max(min).min(max);
         }
-    }
-
-    fn add_slide_width_ratio(&self, slide_width: f64) -> StyleUnit {
-        match self {
-            StyleUnit::Unspecified => StyleUnit::SlideWidthRatio(slide_width),
-            StyleUnit::Pixel(px) => StyleUnit::SlideWidthRatio(slide_width).add_pixel(*px),
-            StyleUnit::Point(_) => todo!(),
-            StyleUnit::Percent(percent) => {
-                StyleUnit::SlideWidthRatio(slide_width).add_percent(*percent)
+        match (self.same_unit(&min), self.same_unit(&max)) {
+            (Some((value, min, unit)), Some((_, max, _))) => {
+                unit.with_value(value.max(min).min(max))
+            }
+            _ => {
+                StyleUnit::Expression(CalcExpression::new(CalcNode::Clamp(min, self.clone(), max)))
             }
-            StyleUnit::SlideWidthRatio(this) => StyleUnit::SlideWidthRatio(this + slide_width),
-            StyleUnit::SlideHeightRatio(_) => todo!(),
-            StyleUnit::Calc(calc_data) => StyleUnit::Calc(CalcData {
-                slide_width: calc_data.slide_width + slide_width,
-                ..*calc_data
-            }),
         }
     }
 
-    fn add_slide_height_ratio(&self, slide_height: f64) -> StyleUnit {
+    pub fn or_zero(&self) -> StyleUnit {
         match self {
-            StyleUnit::Unspecified => StyleUnit::SlideHeightRatio(slide_height),
-            StyleUnit::Pixel(px) => StyleUnit::SlideHeightRatio(slide_height).add_pixel(*px),
-            StyleUnit::Point(_) => todo!(),
-            StyleUnit::Percent(percent) => {
-                StyleUnit::SlideHeightRatio(slide_height).add_percent(*percent)
-            }
-            StyleUnit::SlideWidthRatio(_) => todo!(),
-            StyleUnit::SlideHeightRatio(this) => StyleUnit::SlideHeightRatio(this + slide_height),
-            StyleUnit::Calc(calc_data) => StyleUnit::Calc(CalcData {
-                slide_height: calc_data.slide_height + slide_height,
-                ..*calc_data
-            }),
+            Self::Unspecified => StyleUnit::Pixel(0.0),
+            normal => normal.clone(),
         }
     }
 }
@@ -397,21 +507,12 @@ impl Add for StyleUnit {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        match rhs {
-            StyleUnit::Unspecified => self,
-            StyleUnit::Pixel(px) => self.add_pixel(px),
-            StyleUnit::Point(_pt) => todo!(),
-            StyleUnit::Percent(percent) => self.add_percent(percent),
-            StyleUnit::SlideWidthRatio(slide_width) => self.add_slide_width_ratio(slide_width),
-            StyleUnit::SlideHeightRatio(slide_height) => self.add_slide_height_ratio(slide_height),
-            StyleUnit::Calc(calc_data) => match self {
-                StyleUnit::Unspecified => rhs,
-                StyleUnit::Pixel(px) => StyleUnit::Calc(calc_data.add_pixel(px)),
-                StyleUnit::Point(_) => todo!(),
-                StyleUnit::Percent(percent) => StyleUnit::Calc(calc_data.add_percent(percent)),
-                StyleUnit::SlideWidthRatio(_) => todo!(),
-                StyleUnit::SlideHeightRatio(_) => todo!(),
-                StyleUnit::Calc(_calc_data) => todo!(),
+        match (self, rhs) {
+            (StyleUnit::Unspecified, rhs) => rhs,
+            (lhs, StyleUnit::Unspecified) => lhs,
+            (lhs, rhs) => match (CalcData::from_unit(&lhs), CalcData::from_unit(&rhs)) {
+                (Some(lhs), Some(rhs)) => lhs.add(rhs).simplify(),
+                _ => StyleUnit::Expression(CalcExpression::new(CalcNode::Sum(lhs, rhs))),
             },
         }
     }
@@ -421,27 +522,7 @@ impl Sub for StyleUnit {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        match rhs {
-            StyleUnit::Unspecified => self,
-            StyleUnit::Pixel(px) => self.add_pixel(-px),
-            StyleUnit::Point(_pt) => todo!(),
-            StyleUnit::Percent(percent) => self.add_percent(-percent),
-            StyleUnit::SlideWidthRatio(it) => {
-                StyleUnit::Calc(CalcData::from_units(self, StyleUnit::SlideWidthRatio(-it)))
-            }
-            StyleUnit::SlideHeightRatio(it) => {
-                StyleUnit::Calc(CalcData::from_units(self, StyleUnit::SlideHeightRatio(-it)))
-            }
-            StyleUnit::Calc(_calc_data) => match self {
-                StyleUnit::Unspecified => rhs,
-                StyleUnit::Pixel(_px) => todo!(),
-                StyleUnit::Point(_) => todo!(),
-                StyleUnit::Percent(_percent) => todo!(),
-                StyleUnit::SlideWidthRatio(_) => todo!(),
-                StyleUnit::SlideHeightRatio(_) => todo!(),
-                StyleUnit::Calc(_calc_data) => todo!(),
-            },
-        }
+        self + rhs * -1.0
     }
 }
 
@@ -451,13 +532,29 @@ impl Mul<f64> for StyleUnit {
     fn mul(self, rhs: f64) -> Self::Output {
         match self {
             StyleUnit::Unspecified => self,
-            StyleUnit::Pixel(it) => StyleUnit::Pixel(it * rhs),
-            StyleUnit::Point(it) => StyleUnit::Point(it * rhs),
-            StyleUnit::Percent(it) => StyleUnit::Percent(it * rhs),
-            StyleUnit::SlideWidthRatio(it) => StyleUnit::SlideWidthRatio(it * rhs),
-            StyleUnit::SlideHeightRatio(it) => StyleUnit::SlideHeightRatio(it * rhs),
-            StyleUnit::Calc(calc_data) => todo!(),
+            StyleUnit::Calc(calc_data) => calc_data.scale(rhs).simplify(),
+            StyleUnit::Token(_) | StyleUnit::Expression(_) if rhs == 1.0 => self,
+            StyleUnit::Token(_) | StyleUnit::Expression(_) => {
+                StyleUnit::Expression(CalcExpression::new(CalcNode::Product(self, rhs)))
+            }
+            linear => match linear.as_linear() {
+                Some((unit, value)) => unit.with_value(value * rhs),
+                None => linear,
+            },
+        }
+    }
+}
+
+impl Div<f64> for StyleUnit {
+    type Output = StyleUnit;
+
+    /// Dividing by zero leaves the unit unspecified, since css cannot
+    /// represent the result.
+    fn div(self, rhs: f64) -> Self::Output {
+        if rhs == 0.0 {
+            return StyleUnit::Unspecified;
         }
+        self * (1.0 / rhs)
     }
 }
 
@@ -467,23 +564,148 @@ pub enum StyleUnitParseError {
     UnknownUnits,
 }
 
+/// Splits a literal like `12.5px` into its number and its unit.
+fn split_unit(s: &str) -> Result<(f64, &str), StyleUnitParseError> {
+    let split_index = s
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .count();
+    let (number, unit) = s.split_at(split_index);
+    let number = f64::from_str(number).map_err(|e| StyleUnitParseError::ParseFloatError(e))?;
+    Ok((number, unit))
+}
+
 impl FromStr for StyleUnit {
     type Err = StyleUnitParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let split_index = s
-            .chars()
-            .take_while(|c| c.is_ascii_digit() || *c == '.')
-            .count();
-        let (number, unit) = s.split_at(split_index);
-        let number = f64::from_str(number).map_err(|e| StyleUnitParseError::ParseFloatError(e))?;
+        let (number, unit) = split_unit(s)?;
         Ok(match unit {
             "%" => StyleUnit::Percent(number),
             "px" => StyleUnit::Pixel(number),
             "pt" => StyleUnit::Point(number),
             "sw" => StyleUnit::SlideWidthRatio(number),
             "sh" => StyleUnit::SlideHeightRatio(number),
+            "em" => StyleUnit::Em(number),
+            "rem" => StyleUnit::Rem(number),
+            "vw" => StyleUnit::ViewportWidth(number),
+            "vh" => StyleUnit::ViewportHeight(number),
+            "cm" => StyleUnit::Centimeter(number),
+            "mm" => StyleUnit::Millimeter(number),
+            "in" => StyleUnit::Inch(number),
+            _ => return Err(StyleUnitParseError::UnknownUnits),
+        })
+    }
+}
+
+#[derive(Debug, strum::Display, Clone, Copy, PartialEq)]
+pub enum Angle {
+    #[strum(to_string = "{0}deg")]
+    Degree(f64),
+    #[strum(to_string = "{0}turn")]
+    Turn(f64),
+}
+
+impl Angle {
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Angle::Degree(it) | Angle::Turn(it) => *it == 0.0,
+        }
+    }
+}
+
+impl Default for Angle {
+    fn default() -> Self {
+        Angle::Degree(0.0)
+    }
+}
+
+impl FromStr for Angle {
+    type Err = StyleUnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = split_unit(s)?;
+        Ok(match unit {
+            "deg" => Angle::Degree(number),
+            "turn" => Angle::Turn(number),
             _ => return Err(StyleUnitParseError::UnknownUnits),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adding_the_same_unit_stays_simple() {
+        let sum = StyleUnit::Pixel(1.0) + StyleUnit::Pixel(2.0);
+        assert_eq!(sum, StyleUnit::Pixel(3.0));
+        assert_eq!(StyleUnit::Unspecified + sum.clone(), sum);
+    }
+
+    #[test]
+    fn adding_different_units_emits_calc() {
+        let sum = StyleUnit::Pixel(10.0) + StyleUnit::SlideHeightRatio(0.5);
+        assert!(matches!(sum, StyleUnit::Calc(_)));
+        assert_eq!(sum.to_string(), "calc(10px + 0.5 * var(--slide-height))");
+    }
+
+    #[test]
+    fn subtracting_cancels_units() {
+        let difference = StyleUnit::Pixel(10.0) - StyleUnit::Percent(5.0);
+        assert_eq!(difference.to_string(), "calc(10px - 5%)");
+        assert_eq!(difference + StyleUnit::Percent(5.0), StyleUnit::Pixel(10.0));
+        assert_eq!(
+            StyleUnit::Em(1.0) - StyleUnit::Em(1.0),
+            StyleUnit::Pixel(0.0)
+        );
+    }
+
+    #[test]
+    fn scaling_scales_every_term() {
+        let sum = StyleUnit::Pixel(10.0) + StyleUnit::Percent(5.0);
+        assert_eq!((sum.clone() * 2.0).to_string(), "calc(20px + 10%)");
+        assert_eq!((sum.clone() / 2.0).to_string(), "calc(5px + 2.5%)");
+        assert_eq!(sum / 0.0, StyleUnit::Unspecified);
+    }
+
+    #[test]
+    fn calc_data_simplifies_to_single_units() {
+        let data = CalcData::from_unit(&StyleUnit::Rem(2.0)).unwrap();
+        assert_eq!(data.simplify(), StyleUnit::Rem(2.0));
+        assert_eq!(CalcData::default().simplify(), StyleUnit::Pixel(0.0));
+        let data = data.add(CalcData::from_unit(&StyleUnit::Inch(1.0)).unwrap());
+        assert_eq!(data.scale(-1.0).simplify().to_string(), "calc(-2rem - 1in)");
+    }
+
+    #[test]
+    fn max_and_min_of_different_units_are_emitted() {
+        let (px, percent) = (StyleUnit::Pixel(1.0), StyleUnit::Percent(50.0));
+        assert_eq!(px.max(StyleUnit::Pixel(2.0)), StyleUnit::Pixel(2.0));
+        assert_eq!(px.min(StyleUnit::Pixel(2.0)), px);
+        assert_eq!(px.max(StyleUnit::Unspecified), px);
+        assert_eq!(px.max(percent.clone()).to_string(), "max(1px, 50%)");
+        assert_eq!(px.min(percent).to_string(), "min(1px, 50%)");
+    }
+
+    #[test]
+    fn clamp_is_emitted_for_different_units() {
+        let (min, max) = (StyleUnit::Pixel(10.0), StyleUnit::Pixel(100.0));
+        assert_eq!(StyleUnit::Pixel(5.0).clamp(min.clone(), max.clone()), min);
+        assert_eq!(
+            StyleUnit::Percent(50.0).clamp(min, max).to_string(),
+            "clamp(10px, 50%, 100px)"
+        );
+    }
+
+    #[test]
+    fn expressions_with_tokens_are_nested() {
+        let token = StyleUnit::Token(TokenName::new("gap"));
+        assert_eq!((token.clone() * 2.0).to_string(), "calc(var(--gap) * 2)");
+        assert_eq!(
+            (token + StyleUnit::Pixel(1.0)).to_string(),
+            "calc(var(--gap) + 1px)"
+        );
+    }
+}
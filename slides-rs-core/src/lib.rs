@@ -22,6 +22,8 @@ mod elements;
 pub use elements::*;
 pub mod animations;
 mod output;
+mod tokens;
+pub use tokens::*;
 
 #[allow(dead_code)]
 pub struct Index<T> {
@@ -41,6 +43,11 @@ impl<T> Index<T> {
 pub struct Presentation {
     slides: Vec<Slide>,
     stylings: Vec<DynamicElementStyling>,
+    /// Names of the stylings declared by the theme.
+    theme_stylings: HashSet<String>,
+    tokens: Vec<(TokenName, TokenValue)>,
+    /// Names of the tokens declared by the theme.
+    theme_tokens: HashSet<TokenName>,
     extern_texts: HashMap<FilePlacement, String>,
     used_files: Vec<PathBuf>,
     referenced_files: Vec<PathBuf>,
@@ -51,6 +58,9 @@ impl Presentation {
         Self {
             slides: Vec::new(),
             stylings: Vec::new(),
+            theme_stylings: HashSet::new(),
+            tokens: Vec::new(),
+            theme_tokens: HashSet::new(),
             extern_texts: HashMap::new(),
             used_files: Vec::new(),
             referenced_files: Vec::new(),
@@ -73,10 +83,6 @@ impl Presentation {
             <head>
             <link href="style.css" rel="stylesheet"/>
             <script src="navigation.js"></script>
-
-            <!-- For Google font! -->
-            <link rel="preconnect" href="https://fonts.googleapis.com">
-            <link rel="preconnect" href="https://fonts.gstatic.com" crossorigin>
             <script>
                 function init() {{
                     init_navigation();
@@ -103,21 +109,24 @@ impl Presentation {
                 .unwrap_or(&String::new())
         )?;
 
-        let mut google_font_references = HashSet::new();
+        let mut fonts = FontReferences::new();
         for slide in &self.slides {
-            slide.collect_google_font_references(&mut google_font_references)?;
+            slide.collect_font_references(&mut fonts)?;
         }
 
         for styling in &self.stylings {
-            styling.collect_google_font_references(&mut google_font_references)?;
+            styling.collect_font_references(&mut fonts)?;
         }
 
-        for google_font in google_font_references {
-            writeln!(
-                emitter.raw_html(),
-                r#"<link href="https://fonts.googleapis.com/css2?family={google_font}" rel="stylesheet">"#
-            )?;
+        for (_, value) in &self.tokens {
+            if let TokenValue::Font(font) = value {
+                fonts.insert(font, None);
+            }
         }
+        fonts.resolve_inherited(&self.stylings);
+        fonts.resolve_tokens(&self.tokens);
+
+        Self::output_fonts(&fonts, emitter)?;
 
         if let Some(text) = self.extern_texts.get(&FilePlacement::HtmlHead) {
             writeln!(emitter.raw_html(), "{text}")?;
@@ -128,6 +137,13 @@ impl Presentation {
             r#"</head>
             <body onload="init()" onkeydown="keydown(event)">"#
         )?;
+        if !self.tokens.is_empty() {
+            writeln!(emitter.raw_css(), ":root {{")?;
+            for (name, value) in &self.tokens {
+                writeln!(emitter.raw_css(), "    {}: {value};", name.css_property())?;
+            }
+            writeln!(emitter.raw_css(), "}}")?;
+        }
         for (index, mut slide) in self.slides.into_iter().enumerate() {
             slide.output_to_html(emitter)?
         }
@@ -138,6 +154,7 @@ impl Presentation {
                 &format!(".{}", styling.name()),
                 emitter.raw_css(),
             )?;
+            styling.add_files(emitter)?;
         }
         for file in self.referenced_files {
             emitter.add_file(file)?;
@@ -147,9 +164,146 @@ impl Presentation {
         Ok(())
     }
 
+    /// Declares the fonts used by the presentation. Font files and, if there
+    /// is a font cache, google fonts are copied into the output directory,
+    /// so that the presentation works without an internet connection.
+    fn output_fonts(
+        fonts: &FontReferences,
+        emitter: &mut PresentationEmitter<std::fs::File>,
+    ) -> Result<()> {
+        for (family, path) in fonts.font_files() {
+            emitter.add_file(path)?;
+            writeln!(
+                emitter.raw_css(),
+                r#"@font-face {{
+    font-family: "{family}";
+    src: url("{}");
+}}"#,
+                path.display()
+            )?;
+        }
+
+        match emitter.font_cache().map(PathBuf::from) {
+            Some(font_cache) => {
+                for (family, weights) in fonts.google_fonts() {
+                    for weight in weights {
+                        let file = format!("{weight}.woff2");
+                        let destination = PathBuf::from("fonts").join(family).join(&file);
+                        writeln!(
+                            emitter.raw_css(),
+                            r#"@font-face {{
+    font-family: "{family}";
+    font-weight: {weight};
+    src: url("{}");
+}}"#,
+                            destination.display()
+                        )?;
+                        emitter
+                            .add_vendored_file(font_cache.join(family).join(file), destination)?;
+                    }
+                }
+            }
+            None if fonts.google_fonts().next().is_some() => {
+                writeln!(
+                    emitter.raw_html(),
+                    r#"<link rel="preconnect" href="https://fonts.googleapis.com">
+            <link rel="preconnect" href="https://fonts.gstatic.com" crossorigin>"#
+                )?;
+                for (google_font, _) in fonts.google_fonts() {
+                    writeln!(
+                        emitter.raw_html(),
+                        r#"<link href="https://fonts.googleapis.com/css2?family={google_font}" rel="stylesheet">"#
+                    )?;
+                }
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Adds a styling of the presentation. Stylings with the same name, like
+    /// the default stylings of an element, are merged, so that the later one
+    /// takes precedence. Stylings of the theme take precedence over all
+    /// stylings of the presentation.
     pub fn add_dynamic_styling(&mut self, styling: DynamicElementStyling) -> StylingReference {
         let name = styling.name().to_owned();
-        self.stylings.push(styling);
+        match self.stylings.iter_mut().find(|s| s.name() == name) {
+            Some(existing) if self.theme_stylings.contains(&name) => {
+                let theme = std::mem::replace(existing, styling);
+                existing.merge(&theme);
+            }
+            Some(existing) => existing.merge(&styling),
+            None => self.stylings.push(styling),
+        }
+        unsafe { StylingReference::from_raw(name) }
+    }
+
+    /// Adds a styling of the theme. It takes precedence over the stylings of
+    /// the presentation with the same name, regardless of their order.
+    pub fn add_theme_styling(&mut self, styling: DynamicElementStyling) -> StylingReference {
+        let name = styling.name().to_owned();
+        self.theme_stylings.insert(name.clone());
+        match self.stylings.iter_mut().find(|s| s.name() == name) {
+            Some(existing) => existing.merge(&styling),
+            None => self.stylings.push(styling),
+        }
+        unsafe { StylingReference::from_raw(name) }
+    }
+
+    /// Adds a token of the presentation, which replaces the value of a token
+    /// with the same name. Tokens of the theme take precedence over all
+    /// tokens of the presentation, so the value the token ends up with is
+    /// returned.
+    pub fn add_token(&mut self, name: TokenName, value: TokenValue) -> &TokenValue {
+        let index = match self.tokens.iter().position(|(n, _)| *n == name) {
+            Some(index) => {
+                if !self.theme_tokens.contains(&name) {
+                    self.tokens[index].1 = value;
+                }
+                index
+            }
+            None => {
+                self.tokens.push((name, value));
+                self.tokens.len() - 1
+            }
+        };
+        &self.tokens[index].1
+    }
+
+    /// Adds a token of the theme. It takes precedence over the tokens of the
+    /// presentation with the same name, regardless of their order.
+    pub fn add_theme_token(&mut self, name: TokenName, value: TokenValue) {
+        self.theme_tokens.insert(name.clone());
+        match self.tokens.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.tokens.push((name, value)),
+        }
+    }
+
+    pub fn find_styling(&self, reference: &StylingReference) -> Option<&DynamicElementStyling> {
+        let name = reference.to_string();
+        self.stylings.iter().find(|s| s.name() == name)
+    }
+
+    /// Merges several stylings into one, so that later stylings take
+    /// precedence over earlier ones, regardless of their order in the css.
+    pub fn combine_stylings(&mut self, references: &[StylingReference]) -> StylingReference {
+        let name = references
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join("--");
+        if self.stylings.iter().all(|s| s.name() != name) {
+            let mut stylings = references.iter().filter_map(|r| self.find_styling(r));
+            let mut combined = stylings
+                .next()
+                .expect("at least one styling")
+                .derive(name.clone());
+            for styling in stylings {
+                combined.merge(styling);
+            }
+            self.stylings.push(combined);
+        }
         unsafe { StylingReference::from_raw(name) }
     }
 
@@ -189,6 +343,10 @@ impl Presentation {
         self.slides.len()
     }
 
+    pub fn add_used_file(&mut self, path: impl Into<PathBuf>) {
+        self.used_files.push(path.into());
+    }
+
     pub fn used_files(&self) -> &[PathBuf] {
         &self.used_files
     }
@@ -249,6 +407,7 @@ impl Slide {
         let id = self.name();
         self.styling
             .to_css_rule(ToCssLayout::unknown(), &format!("#{id}"), emitter.raw_css())?;
+        self.styling.add_files(emitter)?;
         writeln!(
             emitter.raw_html(),
             "<section id=\"{id}\" class=\"slide\" data-step-count={}>",
@@ -260,7 +419,7 @@ impl Slide {
                 emitter,
                 WebRenderableContext {
                     layout: ToCssLayout {
-                        outer_padding: self.styling.base().padding,
+                        outer_padding: self.styling.base().padding.clone(),
                         grid_data: None,
                     },
                     slide_name: id.clone(),
@@ -271,9 +430,9 @@ impl Slide {
         Ok(())
     }
 
-    fn collect_google_font_references(&self, fonts: &mut HashSet<String>) -> Result<()> {
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()> {
         for element in &self.elements {
-            element.collect_google_font_references(fonts)?;
+            element.collect_font_references(fonts)?;
         }
         Ok(())
     }
@@ -313,6 +472,10 @@ impl Slide {
         self.elements.push(element);
     }
 
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
     pub fn set_step_count(&mut self, step_count: usize) {
         self.step_count = step_count;
     }
@@ -1,4 +1,8 @@
-use std::{fs::File, io::Write, path::PathBuf};
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use crate::{BASE_STYLE, NAVIGATION_JS};
 
@@ -8,6 +12,10 @@ pub struct PresentationEmitter<W: Write> {
     css: W,
     js: W,
     referenced_files: Vec<PathBuf>,
+    /// Files from outside of the presentation and where they are copied to
+    /// in the output directory.
+    vendored_files: Vec<(PathBuf, PathBuf)>,
+    font_cache: Option<PathBuf>,
 }
 
 impl PresentationEmitter<File> {
@@ -25,6 +33,8 @@ impl PresentationEmitter<File> {
             css,
             js,
             referenced_files: Vec::new(),
+            vendored_files: Vec::new(),
+            font_cache: None,
         })
     }
 
@@ -38,6 +48,16 @@ impl PresentationEmitter<File> {
             _ = to.parent().map(|p| std::fs::create_dir(p)).transpose();
             std::fs::copy(file, to)?;
         }
+        for (file, to) in &self.vendored_files {
+            let to = self.directory.join(to);
+            if !is_outdated(&to, file) {
+                continue;
+            }
+            if let Some(parent) = to.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(file, to)?;
+        }
         Ok(())
     }
 
@@ -46,7 +66,29 @@ impl PresentationEmitter<File> {
     }
 }
 
+/// Whether `copy` is missing or older than its `source`, like when the font
+/// cache was updated since the last build.
+fn is_outdated(copy: &Path, source: &Path) -> bool {
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified());
+    match (modified(copy), modified(source)) {
+        (Ok(copy), Ok(source)) => copy < source,
+        _ => true,
+    }
+}
+
 impl<W: Write> PresentationEmitter<W> {
+    /// Google fonts are copied from the font cache instead of being loaded
+    /// from the internet. The cache contains a directory for every font
+    /// family with a `<weight>.woff2` file for every font weight, like
+    /// `Roboto/700.woff2`.
+    pub fn set_font_cache(&mut self, font_cache: impl Into<PathBuf>) {
+        self.font_cache = Some(font_cache.into());
+    }
+
+    pub fn font_cache(&self) -> Option<&Path> {
+        self.font_cache.as_deref()
+    }
+
     pub fn raw_html(&mut self) -> &mut W {
         &mut self.html
     }
@@ -70,4 +112,22 @@ impl<W: Write> PresentationEmitter<W> {
         self.referenced_files.push(path);
         Ok(())
     }
+
+    /// Adds a file from outside of the presentation, which is copied to
+    /// `destination` inside of the output directory.
+    pub fn add_vendored_file(
+        &mut self,
+        path: impl Into<PathBuf>,
+        destination: impl Into<PathBuf>,
+    ) -> std::io::Result<()> {
+        let path = path.into();
+        if !path.exists() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("File not found: {}", path.display()),
+            ));
+        }
+        self.vendored_files.push((path, destination.into()));
+        Ok(())
+    }
 }
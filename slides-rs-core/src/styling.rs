@@ -2,8 +2,18 @@ use convert_case::{Case, Casing};
 use enum_dispatch::enum_dispatch;
 use struct_field_names_as_array::FieldNamesAsSlice;
 
-use crate::{GridEntry, HorizontalAlignment, Result, StyleUnit, Thickness, VerticalAlignment};
-use std::{any::type_name, fmt::Display, io::Write, ops::Deref};
+use crate::{
+    Angle, FontSize, GridEntry, HorizontalAlignment, ImageSource, Position, Result, StyleUnit,
+    Thickness, TokenName, TokenValue, VerticalAlignment, output::PresentationEmitter,
+};
+use std::{
+    any::type_name,
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    io::Write,
+    ops::Deref,
+    path::PathBuf,
+};
 
 #[enum_dispatch]
 pub trait ToCss {
@@ -25,10 +35,7 @@ pub trait ToCss {
 
     fn to_css_style(&self, layout: ToCssLayout) -> String;
 
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut std::collections::HashSet<String>,
-    ) -> Result<()>;
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()>;
 }
 
 impl ToCss for () {
@@ -40,10 +47,7 @@ impl ToCss for () {
         String::new()
     }
 
-    fn collect_google_font_references(
-        &self,
-        _fonts: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
+    fn collect_font_references(&self, _fonts: &mut FontReferences) -> Result<()> {
         Ok(())
     }
 }
@@ -71,9 +75,30 @@ pub enum Styling {
     Label(LabelStyling),
     Image(ImageStyling),
     Slide(SlideStyling),
+    Grid(GridStyling),
+    Flex(FlexStyling),
     CustomElement(()),
 }
 
+impl Styling {
+    /// Applies every value set in `other` on top of this styling. Stylings for
+    /// different kinds of elements only share their base.
+    fn merge(&mut self, other: &Styling) {
+        if matches!(self, Styling::CustomElement(())) {
+            *self = other.clone();
+            return;
+        }
+        match (self, other) {
+            (Styling::Label(this), Styling::Label(other)) => this.merge(other),
+            (Styling::Image(this), Styling::Image(other)) => this.merge(other),
+            (Styling::Slide(this), Styling::Slide(other)) => this.merge(other),
+            (Styling::Grid(this), Styling::Grid(other)) => this.merge(other),
+            (Styling::Flex(this), Styling::Flex(other)) => this.merge(other),
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DynamicElementStyling {
     name: String,
@@ -90,6 +115,27 @@ impl DynamicElementStyling {
         &mut self.base
     }
 
+    /// Creates a styling called `name`, which starts out with every value of
+    /// this styling.
+    pub fn derive(&self, name: String) -> DynamicElementStyling {
+        DynamicElementStyling {
+            name,
+            base: self.base.clone(),
+            specific: self.specific.clone(),
+        }
+    }
+
+    /// Applies every value set in `other` on top of this styling, so that
+    /// `other` takes precedence over it.
+    pub fn merge(&mut self, other: &DynamicElementStyling) {
+        self.base.merge(&other.base);
+        self.specific.merge(&other.specific);
+    }
+
+    pub(crate) fn add_files<W: Write>(&self, emitter: &mut PresentationEmitter<W>) -> Result<()> {
+        self.base.add_files(emitter)
+    }
+
     pub fn as_label_mut(&mut self) -> &mut LabelStyling {
         match &mut self.specific {
             Styling::Label(label_styling) => label_styling,
@@ -108,6 +154,18 @@ impl DynamicElementStyling {
             unexpected => unreachable!("Expected Slide, found {unexpected}"),
         }
     }
+    pub fn as_grid_mut(&mut self) -> &mut GridStyling {
+        match &mut self.specific {
+            Styling::Grid(grid_styling) => grid_styling,
+            unexpected => unreachable!("Expected Grid, found {unexpected}"),
+        }
+    }
+    pub fn as_flex_mut(&mut self) -> &mut FlexStyling {
+        match &mut self.specific {
+            Styling::Flex(flex_styling) => flex_styling,
+            unexpected => unreachable!("Expected Flex, found {unexpected}"),
+        }
+    }
 }
 
 impl ToCss for DynamicElementStyling {
@@ -125,11 +183,8 @@ impl ToCss for DynamicElementStyling {
         unreachable!("PANIC");
     }
 
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
-        self.specific.collect_google_font_references(fonts)
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()> {
+        self.specific.collect_font_references(fonts)
     }
 
     fn class_name(&self) -> String {
@@ -137,18 +192,115 @@ impl ToCss for DynamicElementStyling {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum Filter {
     #[default]
     Unspecified,
     Brightness(f64),
+    Blur(StyleUnit),
+    Grayscale(f64),
+    Contrast(f64),
+    Saturate(f64),
+    Sepia(f64),
+    HueRotate(Angle),
+    Invert(f64),
+    Opacity(f64),
+    DropShadow {
+        x: StyleUnit,
+        y: StyleUnit,
+        blur: StyleUnit,
+        color: Color,
+    },
+    /// Filters applied one after another, in order.
+    Chain(Vec<Filter>),
 }
 
 impl Filter {
+    /// Combines the filters into a single one. Nested chains are flattened
+    /// and unspecified filters are dropped.
+    pub fn chain(filters: impl IntoIterator<Item = Filter>) -> Self {
+        let mut result = Vec::new();
+        for filter in filters {
+            match filter {
+                Filter::Unspecified => {}
+                Filter::Chain(filters) => result.extend(filters),
+                filter => result.push(filter),
+            }
+        }
+        match result.len() {
+            0 => Filter::Unspecified,
+            1 => result.pop().expect("len is 1"),
+            _ => Filter::Chain(result),
+        }
+    }
+
     pub fn to_css(&self) -> String {
         match self {
             Filter::Unspecified => "unset".into(),
             Filter::Brightness(b) => format!("brightness({b})"),
+            Filter::Blur(radius) => format!("blur({radius})"),
+            Filter::Grayscale(g) => format!("grayscale({g})"),
+            Filter::Contrast(c) => format!("contrast({c})"),
+            Filter::Saturate(s) => format!("saturate({s})"),
+            Filter::Sepia(s) => format!("sepia({s})"),
+            Filter::HueRotate(angle) => format!("hue-rotate({angle})"),
+            Filter::Invert(i) => format!("invert({i})"),
+            Filter::Opacity(o) => format!("opacity({o})"),
+            Filter::DropShadow { x, y, blur, color } => {
+                format!("drop-shadow({x} {y} {blur} {color})")
+            }
+            Filter::Chain(filters) => filters
+                .iter()
+                .map(Filter::to_css)
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Transform {
+    #[default]
+    Identity,
+    Rotate(Angle),
+    Scale(f64, f64),
+    Skew(Angle, Angle),
+    Translate(StyleUnit, StyleUnit),
+    /// Transforms composed from left to right, like a css transform list.
+    Chain(Vec<Transform>),
+}
+
+impl Transform {
+    /// Combines the transforms into a single one. Nested chains are
+    /// flattened and identities are dropped.
+    pub fn chain(transforms: impl IntoIterator<Item = Transform>) -> Self {
+        let mut result = Vec::new();
+        for transform in transforms {
+            match transform {
+                Transform::Identity => {}
+                Transform::Chain(transforms) => result.extend(transforms),
+                transform => result.push(transform),
+            }
+        }
+        match result.len() {
+            0 => Transform::Identity,
+            1 => result.pop().expect("len is 1"),
+            _ => Transform::Chain(result),
+        }
+    }
+
+    pub fn to_css(&self) -> String {
+        match self {
+            Transform::Identity => "none".into(),
+            Transform::Rotate(angle) => format!("rotate({angle})"),
+            Transform::Scale(x, y) => format!("scale({x}, {y})"),
+            Transform::Skew(x, y) => format!("skew({x}, {y})"),
+            Transform::Translate(x, y) => format!("translate({x}, {y})"),
+            Transform::Chain(transforms) => transforms
+                .iter()
+                .map(Transform::to_css)
+                .collect::<Vec<_>>()
+                .join(" "),
         }
     }
 }
@@ -164,7 +316,13 @@ pub struct BaseElementStyling {
     pub width: StyleUnit,
     pub height: StyleUnit,
     pub is_visible: bool,
-    pub rotate: i64,
+    pub rotate: Angle,
+    pub border: Border,
+    pub border_radius: StyleUnit,
+    pub box_shadow: Option<BoxShadow>,
+    pub opacity: Option<f64>,
+    pub transform: Transform,
+    pub transform_origin: Option<Position>,
     z_index: Option<usize>,
 }
 
@@ -205,8 +363,82 @@ impl BaseElementStyling {
         self.filter = filter;
     }
 
-    pub fn set_rotation(&mut self, degree: i64) {
-        self.rotate = degree;
+    pub(crate) fn add_files<W: Write>(&self, emitter: &mut PresentationEmitter<W>) -> Result<()> {
+        self.background.add_files(emitter)
+    }
+
+    pub fn set_rotation(&mut self, angle: Angle) {
+        self.rotate = angle;
+    }
+
+    pub fn set_border(&mut self, border: Border) {
+        self.border = border;
+    }
+
+    pub fn set_border_radius(&mut self, border_radius: StyleUnit) {
+        self.border_radius = border_radius;
+    }
+
+    pub fn set_box_shadow(&mut self, box_shadow: BoxShadow) {
+        self.box_shadow = Some(box_shadow);
+    }
+
+    pub fn set_opacity(&mut self, opacity: f64) {
+        self.opacity = Some(opacity);
+    }
+
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    pub fn set_transform_origin(&mut self, transform_origin: Position) {
+        self.transform_origin = Some(transform_origin);
+    }
+
+    /// Applies every value set in `other` on top of this styling.
+    pub fn merge(&mut self, other: &BaseElementStyling) {
+        if other.background != Background::Unspecified {
+            self.background = other.background.clone();
+        }
+        if !matches!(other.halign, HorizontalAlignment::Unset) {
+            self.halign = other.halign;
+        }
+        if !matches!(other.valign, VerticalAlignment::Unset) {
+            self.valign = other.valign;
+        }
+        self.margin.merge(&other.margin);
+        self.padding.merge(&other.padding);
+        if other.filter != Filter::Unspecified {
+            self.filter = other.filter.clone();
+        }
+        if other.width != StyleUnit::Unspecified {
+            self.width = other.width.clone();
+        }
+        if other.height != StyleUnit::Unspecified {
+            self.height = other.height.clone();
+        }
+        if !other.rotate.is_zero() {
+            self.rotate = other.rotate;
+        }
+        self.border.merge(&other.border);
+        if other.border_radius != StyleUnit::Unspecified {
+            self.border_radius = other.border_radius.clone();
+        }
+        if other.box_shadow.is_some() {
+            self.box_shadow = other.box_shadow.clone();
+        }
+        if other.opacity.is_some() {
+            self.opacity = other.opacity;
+        }
+        if other.transform != Transform::Identity {
+            self.transform = other.transform.clone();
+        }
+        if other.transform_origin.is_some() {
+            self.transform_origin = other.transform_origin.clone();
+        }
+        if other.z_index.is_some() {
+            self.z_index = other.z_index;
+        }
     }
 }
 
@@ -246,7 +478,7 @@ impl ToCss for BaseElementStyling {
             VerticalAlignment::Stretch => {
                 let top = self.margin.top.max(layout.outer_padding.top);
                 let bottom = self.margin.bottom.max(layout.outer_padding.bottom);
-                let height = StyleUnit::Percent(100.0) - top - bottom;
+                let height = StyleUnit::Percent(100.0) - top.clone() - bottom.clone();
                 let top = top.or_zero();
                 let bottom = bottom.or_zero();
                 was_height_already_emitted = true;
@@ -283,7 +515,7 @@ impl ToCss for BaseElementStyling {
             HorizontalAlignment::Stretch => {
                 let left = self.margin.left.max(layout.outer_padding.left);
                 let right = self.margin.right.max(layout.outer_padding.right);
-                let width = StyleUnit::Percent(100.0) - left - right;
+                let width = StyleUnit::Percent(100.0) - left.clone() - right.clone();
                 let left = left.or_zero();
                 let right = right.or_zero();
                 was_width_already_emitted = true;
@@ -303,13 +535,20 @@ impl ToCss for BaseElementStyling {
             writeln!(result, "    width: {};", self.width).expect("infallible");
         }
 
+        // The translation centering the element uses its own property, so
+        // that it is applied before and never replaced by the transform of
+        // the element, even if both come from different rules.
         if translate != (0.0, 0.0) {
-            writeln!(
-                result,
-                "    transform: translate({}%, {}%);",
-                translate.0, translate.1
-            )
-            .expect("infallible");
+            writeln!(result, "    translate: {}% {}%;", translate.0, translate.1)
+                .expect("infallible");
+        }
+        if self.transform != Transform::Identity {
+            writeln!(result, "    transform: {};", self.transform.to_css()).expect("infallible");
+        }
+
+        if let Some(origin) = &self.transform_origin {
+            writeln!(result, "    transform-origin: {} {};", origin.x, origin.y)
+                .expect("infallible");
         }
 
         if self.padding != Thickness::default() {
@@ -328,16 +567,27 @@ impl ToCss for BaseElementStyling {
             writeln!(result, "    filter: {};", self.filter.to_css()).expect("infallible");
         }
 
-        if self.rotate != 0 {
-            writeln!(result, "    rotate: {}deg;", self.rotate).expect("infallible");
+        if !self.rotate.is_zero() {
+            writeln!(result, "    rotate: {};", self.rotate).expect("infallible");
+        }
+
+        self.border.write_css(&mut result);
+
+        if self.border_radius != StyleUnit::Unspecified {
+            writeln!(result, "    border-radius: {};", self.border_radius).expect("infallible");
+        }
+
+        if let Some(box_shadow) = &self.box_shadow {
+            writeln!(result, "    box-shadow: {box_shadow};").expect("infallible");
+        }
+
+        if let Some(opacity) = self.opacity {
+            writeln!(result, "    opacity: {opacity};").expect("infallible");
         }
         result
     }
 
-    fn collect_google_font_references(
-        &self,
-        _: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
+    fn collect_font_references(&self, _: &mut FontReferences) -> Result<()> {
         Ok(())
     }
 
@@ -395,9 +645,13 @@ impl<S: ToCss + 'static> ElementStyling<S> {
     pub fn set_background(&mut self, background: Background) {
         self.base.background = background;
     }
+
+    pub(crate) fn add_files<W: Write>(&self, emitter: &mut PresentationEmitter<W>) -> Result<()> {
+        self.base.add_files(emitter)
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ToCssLayout {
     pub outer_padding: Thickness,
     pub grid_data: Option<GridEntry>,
@@ -444,11 +698,8 @@ impl<S: ToCss> ToCss for ElementStyling<S> {
         unreachable!("PANIC");
     }
 
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
-        self.specific.collect_google_font_references(fonts)
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()> {
+        self.specific.collect_font_references(fonts)
     }
 
     fn class_name(&self) -> String {
@@ -527,22 +778,25 @@ impl SlideStyling {
     pub fn new() -> ElementStyling<SlideStyling> {
         ElementStyling::new(Self { text_color: None })
     }
+
+    fn merge(&mut self, other: &SlideStyling) {
+        if other.text_color.is_some() {
+            self.text_color = other.text_color.clone();
+        }
+    }
 }
 
 impl ToCss for SlideStyling {
     fn to_css_style(&self, _layout: ToCssLayout) -> String {
         use std::fmt::Write;
         let mut result = String::new();
-        if let Some(text_color) = self.text_color {
+        if let Some(text_color) = &self.text_color {
             writeln!(result, "    color: {text_color};").expect("infallible");
         }
         result
     }
 
-    fn collect_google_font_references(
-        &self,
-        _: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
+    fn collect_font_references(&self, _: &mut FontReferences) -> Result<()> {
         Ok(())
     }
 
@@ -551,7 +805,7 @@ impl ToCss for SlideStyling {
     }
 }
 
-#[derive(Default, Debug, PartialEq, Eq, strum::Display, Clone)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, strum::Display, Clone)]
 pub enum Font {
     #[strum(to_string = "unset")]
     #[default]
@@ -560,6 +814,12 @@ pub enum Font {
     GoogleFont(String),
     #[strum(to_string = "\"{0}\"")]
     System(String),
+    /// A font file, which is copied to the output and declared with a
+    /// `@font-face` rule.
+    #[strum(to_string = "\"{family}\"")]
+    File { family: String, path: PathBuf },
+    #[strum(to_string = "{0}")]
+    Token(TokenName),
 }
 
 impl Font {
@@ -570,6 +830,97 @@ impl Font {
     pub fn system(name: impl Into<String>) -> Self {
         Self::System(name.into())
     }
+
+    /// Uses the font of the file, named after its file stem.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let family = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self::File { family, path }
+    }
+}
+
+/// The fonts used by a presentation together with their font weights,
+/// which need to be linked or embedded.
+#[derive(Debug, Default)]
+pub struct FontReferences {
+    fonts: HashMap<Font, BTreeSet<usize>>,
+    /// The weights of text without a font, which uses the font of the
+    /// default label styling.
+    inherited_weights: BTreeSet<usize>,
+}
+
+impl FontReferences {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only google fonts, font files and tokens, which can refer to either
+    /// of them, need to be loaded, so other fonts are ignored. Text without a
+    /// font weight uses the regular weight. The weights of text without a
+    /// font are kept until the inherited font is known.
+    pub fn insert(&mut self, font: &Font, weight: Option<usize>) {
+        if *font == Font::Unspecified {
+            self.inherited_weights.insert(weight.unwrap_or(400));
+        } else if matches!(
+            font,
+            Font::GoogleFont(_) | Font::File { .. } | Font::Token(_)
+        ) {
+            self.fonts
+                .entry(font.clone())
+                .or_default()
+                .insert(weight.unwrap_or(400));
+        }
+    }
+
+    /// Adds the weights of text without a font to the font of the default
+    /// label styling, which it inherits.
+    pub fn resolve_inherited(&mut self, stylings: &[DynamicElementStyling]) {
+        let Some(Styling::Label(default)) = stylings
+            .iter()
+            .find(|s| s.name() == "label")
+            .map(|s| &s.specific)
+        else {
+            return;
+        };
+        for weight in std::mem::take(&mut self.inherited_weights) {
+            self.insert(&default.font, Some(weight));
+        }
+    }
+
+    /// Replaces font tokens by the fonts they refer to, so that the weights
+    /// used with a token are loaded for its font.
+    pub fn resolve_tokens(&mut self, tokens: &[(TokenName, TokenValue)]) {
+        for (font, weights) in std::mem::take(&mut self.fonts) {
+            let font = match font {
+                Font::Token(name) => match tokens.iter().find(|(n, _)| *n == name) {
+                    Some((_, TokenValue::Font(font))) => font.clone(),
+                    _ => continue,
+                },
+                font => font,
+            };
+            if matches!(font, Font::GoogleFont(_) | Font::File { .. }) {
+                self.fonts.entry(font).or_default().extend(weights);
+            }
+        }
+    }
+
+    /// The google fonts with the font weights they are used with.
+    pub fn google_fonts(&self) -> impl Iterator<Item = (&str, &BTreeSet<usize>)> {
+        self.fonts.iter().filter_map(|(font, weights)| match font {
+            Font::GoogleFont(name) => Some((name.as_str(), weights)),
+            _ => None,
+        })
+    }
+
+    pub fn font_files(&self) -> impl Iterator<Item = (&str, &PathBuf)> {
+        self.fonts.keys().filter_map(|font| match font {
+            Font::File { family, path } => Some((family.as_str(), path)),
+            _ => None,
+        })
+    }
 }
 
 #[derive(
@@ -603,12 +954,142 @@ impl TextAlign {
     }
 }
 
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::IntoStaticStr,
+    strum::VariantNames,
+)]
+pub enum FontStyle {
+    #[default]
+    #[strum(to_string = "unset")]
+    Unspecified,
+    Normal,
+    Italic,
+    Oblique,
+}
+
+impl SlidesEnum for FontStyle {}
+
+impl FontStyle {
+    pub fn as_css(&self) -> String {
+        self.to_string().to_case(Case::Kebab)
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::IntoStaticStr,
+    strum::VariantNames,
+)]
+pub enum TextDecoration {
+    #[default]
+    #[strum(to_string = "unset")]
+    Unspecified,
+    None,
+    Underline,
+    Overline,
+    LineThrough,
+}
+
+impl SlidesEnum for TextDecoration {}
+
+impl TextDecoration {
+    pub fn as_css(&self) -> String {
+        self.to_string().to_case(Case::Kebab)
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::IntoStaticStr,
+    strum::VariantNames,
+)]
+pub enum TextTransform {
+    #[default]
+    #[strum(to_string = "unset")]
+    Unspecified,
+    None,
+    Uppercase,
+    Lowercase,
+    Capitalize,
+}
+
+impl SlidesEnum for TextTransform {}
+
+impl TextTransform {
+    pub fn as_css(&self) -> String {
+        self.to_string().to_case(Case::Kebab)
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::IntoStaticStr,
+    strum::VariantNames,
+)]
+pub enum WhiteSpace {
+    #[default]
+    #[strum(to_string = "unset")]
+    Unspecified,
+    Normal,
+    Nowrap,
+    Pre,
+    PreWrap,
+    PreLine,
+}
+
+impl SlidesEnum for WhiteSpace {}
+
+impl WhiteSpace {
+    pub fn as_css(&self) -> String {
+        self.to_string().to_case(Case::Kebab)
+    }
+}
+
+/// The styling of a single part of the markdown inside of a label, like its
+/// headings or its inline code.
 #[derive(Default, Debug, Clone, FieldNamesAsSlice, PartialEq)]
 pub struct TextStyling {
     text_color: Option<Color>,
     text_align: TextAlign,
     font: Font,
-    font_size: StyleUnit,
+    font_size: FontSize,
+    font_weight: Option<usize>,
+    font_style: FontStyle,
+    letter_spacing: StyleUnit,
+    line_height: Option<f64>,
+    text_decoration: TextDecoration,
+    text_transform: TextTransform,
+    text_shadow: Option<BoxShadow>,
+    margin: Thickness,
 }
 
 impl TextStyling {
@@ -616,10 +1097,124 @@ impl TextStyling {
         self.text_color = Some(text_color);
     }
 
+    pub fn set_text_align(&mut self, text_align: TextAlign) {
+        self.text_align = text_align;
+    }
+
+    pub fn set_font(&mut self, font: Font) {
+        self.font = font;
+    }
+
+    pub fn set_font_size(&mut self, font_size: StyleUnit) {
+        self.font_size = font_size;
+    }
+
+    pub fn set_font_weight(&mut self, font_weight: usize) {
+        self.font_weight = Some(font_weight);
+    }
+
+    pub fn set_letter_spacing(&mut self, letter_spacing: StyleUnit) {
+        self.letter_spacing = letter_spacing;
+    }
+
+    pub fn set_line_height(&mut self, line_height: f64) {
+        self.line_height = Some(line_height);
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.font_style = font_style;
+    }
+
+    pub fn set_text_decoration(&mut self, text_decoration: TextDecoration) {
+        self.text_decoration = text_decoration;
+    }
+
+    pub fn set_text_transform(&mut self, text_transform: TextTransform) {
+        self.text_transform = text_transform;
+    }
+
+    pub fn set_text_shadow(&mut self, text_shadow: BoxShadow) {
+        self.text_shadow = Some(text_shadow);
+    }
+
+    pub fn set_margin(&mut self, margin: Thickness) {
+        self.margin = margin;
+    }
+
+    fn merge(&mut self, other: &TextStyling) {
+        if other.text_color.is_some() {
+            self.text_color = other.text_color.clone();
+        }
+        if other.text_align != TextAlign::Unspecified {
+            self.text_align = other.text_align;
+        }
+        if other.font != Font::Unspecified {
+            self.font = other.font.clone();
+        }
+        if other.font_size != StyleUnit::Unspecified {
+            self.font_size = other.font_size.clone();
+        }
+        if other.font_weight.is_some() {
+            self.font_weight = other.font_weight;
+        }
+        if other.letter_spacing != StyleUnit::Unspecified {
+            self.letter_spacing = other.letter_spacing.clone();
+        }
+        if other.line_height.is_some() {
+            self.line_height = other.line_height;
+        }
+        if other.font_style != FontStyle::Unspecified {
+            self.font_style = other.font_style;
+        }
+        if other.text_decoration != TextDecoration::Unspecified {
+            self.text_decoration = other.text_decoration;
+        }
+        if other.text_transform != TextTransform::Unspecified {
+            self.text_transform = other.text_transform;
+        }
+        if other.text_shadow.is_some() {
+            self.text_shadow = other.text_shadow.clone();
+        }
+        self.margin.merge(&other.margin);
+    }
+
     fn output_css_statements(&self, w: &mut dyn Write) -> std::io::Result<()> {
-        if let Some(text_color) = self.text_color {
+        if let Some(text_color) = &self.text_color {
             writeln!(w, "    color: {text_color};")?;
         }
+        if self.text_align != TextAlign::Unspecified {
+            writeln!(w, "    text-align: {};", self.text_align.as_css())?;
+        }
+        if self.font != Font::Unspecified {
+            writeln!(w, "    font-family: {};", self.font)?;
+        }
+        if self.font_size != StyleUnit::Unspecified {
+            writeln!(w, "    font-size: {};", self.font_size)?;
+        }
+        if let Some(font_weight) = self.font_weight {
+            writeln!(w, "    font-weight: {font_weight};")?;
+        }
+        if self.letter_spacing != StyleUnit::Unspecified {
+            writeln!(w, "    letter-spacing: {};", self.letter_spacing)?;
+        }
+        if let Some(line_height) = self.line_height {
+            writeln!(w, "    line-height: {line_height};")?;
+        }
+        if self.font_style != FontStyle::Unspecified {
+            writeln!(w, "    font-style: {};", self.font_style.as_css())?;
+        }
+        if self.text_decoration != TextDecoration::Unspecified {
+            writeln!(w, "    text-decoration: {};", self.text_decoration.as_css())?;
+        }
+        if self.text_transform != TextTransform::Unspecified {
+            writeln!(w, "    text-transform: {};", self.text_transform.as_css())?;
+        }
+        if let Some(text_shadow) = &self.text_shadow {
+            writeln!(w, "    text-shadow: {text_shadow};")?;
+        }
+        if self.margin != Thickness::default() {
+            writeln!(w, "    margin: {};", self.margin)?;
+        }
         Ok(())
     }
 }
@@ -627,27 +1222,122 @@ impl TextStyling {
 #[derive(Debug, Clone, FieldNamesAsSlice, Default, PartialEq)]
 pub struct LabelStyling {
     text: TextStyling,
+    h1: TextStyling,
+    h2: TextStyling,
+    h3: TextStyling,
+    h4: TextStyling,
+    h5: TextStyling,
+    h6: TextStyling,
+    list_item: TextStyling,
+    emphasis: TextStyling,
+    strong: TextStyling,
+    code: TextStyling,
+    link: TextStyling,
     text_color: Option<Color>,
     text_align: TextAlign,
     font: Font,
-    font_size: Option<f64>,
+    font_size: FontSize,
     font_weight: Option<usize>,
+    font_style: FontStyle,
+    line_height: Option<f64>,
+    letter_spacing: StyleUnit,
+    text_decoration: TextDecoration,
+    text_transform: TextTransform,
+    text_shadow: Option<BoxShadow>,
+    white_space: WhiteSpace,
+    hyphenate: Option<bool>,
+    column_count: Option<usize>,
 }
 
 impl LabelStyling {
     pub fn new() -> ElementStyling<LabelStyling> {
-        ElementStyling::new(Self {
-            text: TextStyling::default(),
-            text_color: None,
-            text_align: TextAlign::Unspecified,
-            font: Font::Unspecified,
-            font_size: None,
-            font_weight: None,
-        })
-    }
-
-    pub fn set_text_styling(&mut self, text: TextStyling) {
-        self.text = text;
+        ElementStyling::new(Self::default())
+    }
+
+    /// The parts of the markdown of a label, which can be styled on their own,
+    /// by their name in a styling.
+    pub fn text_parts_mut(&mut self) -> [(&'static str, &mut TextStyling); 12] {
+        [
+            ("text", &mut self.text),
+            ("h1", &mut self.h1),
+            ("h2", &mut self.h2),
+            ("h3", &mut self.h3),
+            ("h4", &mut self.h4),
+            ("h5", &mut self.h5),
+            ("h6", &mut self.h6),
+            ("list_item", &mut self.list_item),
+            ("emphasis", &mut self.emphasis),
+            ("strong", &mut self.strong),
+            ("code", &mut self.code),
+            ("link", &mut self.link),
+        ]
+    }
+
+    /// The parts of the markdown of a label by the css selector, which
+    /// matches the html emitted for them.
+    fn text_parts(&self) -> [(&'static str, &TextStyling); 12] {
+        [
+            (".label-text", &self.text),
+            ("h1", &self.h1),
+            ("h2", &self.h2),
+            ("h3", &self.h3),
+            ("h4", &self.h4),
+            ("h5", &self.h5),
+            ("h6", &self.h6),
+            ("li", &self.list_item),
+            (".emphasis", &self.emphasis),
+            ("strong", &self.strong),
+            ("code", &self.code),
+            ("a", &self.link),
+        ]
+    }
+
+    fn merge(&mut self, other: &LabelStyling) {
+        for ((_, this), (_, other)) in self.text_parts_mut().into_iter().zip(other.text_parts()) {
+            this.merge(other);
+        }
+        if other.text_color.is_some() {
+            self.text_color = other.text_color.clone();
+        }
+        if other.text_align != TextAlign::Unspecified {
+            self.text_align = other.text_align;
+        }
+        if other.font != Font::Unspecified {
+            self.font = other.font.clone();
+        }
+        if other.font_size != StyleUnit::Unspecified {
+            self.font_size = other.font_size.clone();
+        }
+        if other.font_weight.is_some() {
+            self.font_weight = other.font_weight;
+        }
+        if other.font_style != FontStyle::Unspecified {
+            self.font_style = other.font_style;
+        }
+        if other.line_height.is_some() {
+            self.line_height = other.line_height;
+        }
+        if other.letter_spacing != StyleUnit::Unspecified {
+            self.letter_spacing = other.letter_spacing.clone();
+        }
+        if other.text_decoration != TextDecoration::Unspecified {
+            self.text_decoration = other.text_decoration;
+        }
+        if other.text_transform != TextTransform::Unspecified {
+            self.text_transform = other.text_transform;
+        }
+        if other.text_shadow.is_some() {
+            self.text_shadow = other.text_shadow.clone();
+        }
+        if other.white_space != WhiteSpace::Unspecified {
+            self.white_space = other.white_space;
+        }
+        if other.hyphenate.is_some() {
+            self.hyphenate = other.hyphenate;
+        }
+        if other.column_count.is_some() {
+            self.column_count = other.column_count;
+        }
     }
 }
 
@@ -664,13 +1354,49 @@ impl LabelStyling {
         self.font = font;
     }
 
-    pub fn set_font_size(&mut self, font_size: f64) {
-        self.font_size = Some(font_size);
+    pub fn set_font_size(&mut self, font_size: StyleUnit) {
+        self.font_size = font_size;
     }
 
     pub fn set_font_weight(&mut self, font_weight: usize) {
         self.font_weight = Some(font_weight);
     }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.font_style = font_style;
+    }
+
+    pub fn set_line_height(&mut self, line_height: f64) {
+        self.line_height = Some(line_height);
+    }
+
+    pub fn set_letter_spacing(&mut self, letter_spacing: StyleUnit) {
+        self.letter_spacing = letter_spacing;
+    }
+
+    pub fn set_text_decoration(&mut self, text_decoration: TextDecoration) {
+        self.text_decoration = text_decoration;
+    }
+
+    pub fn set_text_transform(&mut self, text_transform: TextTransform) {
+        self.text_transform = text_transform;
+    }
+
+    pub fn set_text_shadow(&mut self, text_shadow: BoxShadow) {
+        self.text_shadow = Some(text_shadow);
+    }
+
+    pub fn set_white_space(&mut self, white_space: WhiteSpace) {
+        self.white_space = white_space;
+    }
+
+    pub fn set_hyphenate(&mut self, hyphenate: bool) {
+        self.hyphenate = Some(hyphenate);
+    }
+
+    pub fn set_column_count(&mut self, column_count: usize) {
+        self.column_count = Some(column_count);
+    }
 }
 
 impl ElementStyling<LabelStyling> {
@@ -692,12 +1418,48 @@ impl ElementStyling<LabelStyling> {
         self.specific.text_align = text_align;
     }
 
-    pub fn set_font_size(&mut self, font_size: f64) {
-        self.specific.font_size = Some(font_size);
+    pub fn set_font_size(&mut self, font_size: StyleUnit) {
+        self.specific.set_font_size(font_size);
     }
 
     pub fn set_font_weight(&mut self, font_weight: usize) {
-        self.specific.font_weight = Some(font_weight);
+        self.specific.set_font_weight(font_weight);
+    }
+
+    pub fn set_font_style(&mut self, font_style: FontStyle) {
+        self.specific.set_font_style(font_style);
+    }
+
+    pub fn set_line_height(&mut self, line_height: f64) {
+        self.specific.set_line_height(line_height);
+    }
+
+    pub fn set_letter_spacing(&mut self, letter_spacing: StyleUnit) {
+        self.specific.set_letter_spacing(letter_spacing);
+    }
+
+    pub fn set_text_decoration(&mut self, text_decoration: TextDecoration) {
+        self.specific.set_text_decoration(text_decoration);
+    }
+
+    pub fn set_text_transform(&mut self, text_transform: TextTransform) {
+        self.specific.set_text_transform(text_transform);
+    }
+
+    pub fn set_text_shadow(&mut self, text_shadow: BoxShadow) {
+        self.specific.set_text_shadow(text_shadow);
+    }
+
+    pub fn set_white_space(&mut self, white_space: WhiteSpace) {
+        self.specific.set_white_space(white_space);
+    }
+
+    pub fn set_hyphenate(&mut self, hyphenate: bool) {
+        self.specific.set_hyphenate(hyphenate);
+    }
+
+    pub fn set_column_count(&mut self, column_count: usize) {
+        self.specific.set_column_count(column_count);
     }
 }
 
@@ -712,14 +1474,11 @@ impl ToCss for LabelStyling {
             return Ok(());
         }
         if self.text != TextStyling::default() {
-            writeln!(w, "{selector} .label-text {{")?;
-            self.text.output_css_statements(w)?;
-            writeln!(w, "}}\n")?;
             writeln!(w, "{selector} {{")?;
         } else {
             writeln!(w, "{selector}, {selector} .label-text {{")?;
         }
-        if let Some(text_color) = self.text_color {
+        if let Some(text_color) = &self.text_color {
             writeln!(w, "    color: {text_color};").expect("infallible");
         }
         if self.font != Font::Unspecified {
@@ -728,18 +1487,51 @@ impl ToCss for LabelStyling {
         if self.text_align != TextAlign::Unspecified {
             writeln!(w, "    text-align: {};", self.text_align.as_css()).expect("infallible");
         }
-        if let Some(font_size) = self.font_size {
-            writeln!(
-                w,
-                "    font-size: calc({font_size} * min(16 * 4dvh, 9 * 4dvw) / 16);"
-            )
-            .expect("infallible");
+        if self.font_size != StyleUnit::Unspecified {
+            writeln!(w, "    font-size: {};", self.font_size).expect("infallible");
         }
         if let Some(font_weight) = self.font_weight {
             writeln!(w, "    font-weight: {};", font_weight).expect("infallible");
         }
+        if self.font_style != FontStyle::Unspecified {
+            writeln!(w, "    font-style: {};", self.font_style.as_css())?;
+        }
+        if let Some(line_height) = self.line_height {
+            writeln!(w, "    line-height: {line_height};")?;
+        }
+        if self.letter_spacing != StyleUnit::Unspecified {
+            writeln!(w, "    letter-spacing: {};", self.letter_spacing)?;
+        }
+        if self.text_decoration != TextDecoration::Unspecified {
+            writeln!(w, "    text-decoration: {};", self.text_decoration.as_css())?;
+        }
+        if self.text_transform != TextTransform::Unspecified {
+            writeln!(w, "    text-transform: {};", self.text_transform.as_css())?;
+        }
+        if let Some(text_shadow) = &self.text_shadow {
+            writeln!(w, "    text-shadow: {text_shadow};")?;
+        }
+        if self.white_space != WhiteSpace::Unspecified {
+            writeln!(w, "    white-space: {};", self.white_space.as_css())?;
+        }
+        match self.hyphenate {
+            Some(true) => writeln!(w, "    hyphens: auto;")?,
+            Some(false) => writeln!(w, "    hyphens: manual;")?,
+            None => {}
+        }
+        if let Some(column_count) = self.column_count {
+            writeln!(w, "    column-count: {column_count};")?;
+        }
         writeln!(w, "}}\n")?;
 
+        for (part_selector, part) in self.text_parts() {
+            if part != &TextStyling::default() {
+                writeln!(w, "{selector} {part_selector} {{")?;
+                part.output_css_statements(w)?;
+                writeln!(w, "}}\n")?;
+            }
+        }
+
         Ok(())
     }
 
@@ -747,15 +1539,21 @@ impl ToCss for LabelStyling {
         unreachable!("PANIC!");
     }
 
-    fn collect_google_font_references(
-        &self,
-        fonts: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
-        match &self.font {
-            Font::GoogleFont(name) => {
-                fonts.insert(name.clone());
-            }
-            _ => {}
+    fn collect_font_references(&self, fonts: &mut FontReferences) -> Result<()> {
+        fonts.insert(&self.font, self.font_weight);
+        // Parts without a font of their own use the font of the label.
+        // Headings and strong text are bold by default in browsers.
+        for (selector, part) in self.text_parts() {
+            let font = if part.font == Font::Unspecified {
+                &self.font
+            } else {
+                &part.font
+            };
+            let default_weight = match selector {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "strong" => Some(700),
+                _ => self.font_weight,
+            };
+            fonts.insert(font, part.font_weight.or(default_weight));
         }
         Ok(())
     }
@@ -815,6 +1613,12 @@ impl ImageStyling {
     pub fn new() -> ElementStyling<ImageStyling> {
         ElementStyling::new(ImageStyling::default())
     }
+
+    fn merge(&mut self, other: &ImageStyling) {
+        if other.object_fit != ObjectFit::Unspecified {
+            self.object_fit = other.object_fit;
+        }
+    }
 }
 
 impl ElementStyling<ImageStyling> {
@@ -838,10 +1642,7 @@ impl ToCss for ImageStyling {
         result
     }
 
-    fn collect_google_font_references(
-        &self,
-        _: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
+    fn collect_font_references(&self, _: &mut FontReferences) -> Result<()> {
         Ok(())
     }
 
@@ -881,6 +1682,23 @@ impl GridStyling {
     pub fn new(columns: Vec<GridCellSize>, rows: Vec<GridCellSize>) -> ElementStyling<Self> {
         ElementStyling::new(GridStyling { columns, rows })
     }
+
+    pub fn set_columns(&mut self, columns: Vec<GridCellSize>) {
+        self.columns = columns;
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<GridCellSize>) {
+        self.rows = rows;
+    }
+
+    fn merge(&mut self, other: &GridStyling) {
+        if !other.columns.is_empty() {
+            self.columns = other.columns.clone();
+        }
+        if !other.rows.is_empty() {
+            self.rows = other.rows.clone();
+        }
+    }
 }
 
 impl ToCss for GridStyling {
@@ -918,16 +1736,49 @@ impl ToCss for GridStyling {
         result
     }
 
-    fn collect_google_font_references(
-        &self,
-        _: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
+    fn collect_font_references(&self, _: &mut FontReferences) -> Result<()> {
         Ok(())
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl ElementStyling<GridStyling> {
+    pub fn to_dynamic(self, name: String) -> DynamicElementStyling {
+        let name = if name == "default" {
+            self.class_name()
+        } else {
+            name
+        };
+        DynamicElementStyling {
+            name,
+            base: self.base,
+            specific: self.specific.into(),
+        }
+    }
+
+    pub fn set_columns(&mut self, columns: Vec<GridCellSize>) {
+        self.specific.set_columns(columns);
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<GridCellSize>) {
+        self.specific.set_rows(rows);
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::IntoStaticStr,
+    strum::VariantNames,
+)]
 pub enum FlexDirection {
+    #[default]
+    #[strum(to_string = "unset")]
     Unspecified,
     Row,
     Column,
@@ -935,6 +1786,8 @@ pub enum FlexDirection {
     ColumnReverse,
 }
 
+impl SlidesEnum for FlexDirection {}
+
 impl FlexDirection {
     pub fn to_css(&self) -> String {
         match self {
@@ -958,6 +1811,16 @@ impl FlexStyling {
             direction: FlexDirection::Unspecified,
         })
     }
+
+    pub fn set_direction(&mut self, direction: FlexDirection) {
+        self.direction = direction;
+    }
+
+    fn merge(&mut self, other: &FlexStyling) {
+        if other.direction != FlexDirection::Unspecified {
+            self.direction = other.direction;
+        }
+    }
 }
 
 impl ToCss for FlexStyling {
@@ -975,42 +1838,244 @@ impl ToCss for FlexStyling {
         result
     }
 
-    fn collect_google_font_references(
-        &self,
-        _: &mut std::collections::HashSet<String>,
-    ) -> Result<()> {
+    fn collect_font_references(&self, _: &mut FontReferences) -> Result<()> {
         Ok(())
     }
 }
 
 impl ElementStyling<FlexStyling> {
+    pub fn to_dynamic(self, name: String) -> DynamicElementStyling {
+        let name = if name == "default" {
+            self.class_name()
+        } else {
+            name
+        };
+        DynamicElementStyling {
+            name,
+            base: self.base,
+            specific: self.specific.into(),
+        }
+    }
+
     pub fn set_direction(&mut self, direction: FlexDirection) {
-        self.specific.direction = direction;
+        self.specific.set_direction(direction);
     }
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Clone)]
 pub enum Background {
     #[default]
     Unspecified,
     Color(Color),
+    LinearGradient {
+        angle: Angle,
+        stops: Vec<ColorStop>,
+    },
+    RadialGradient {
+        stops: Vec<ColorStop>,
+    },
+    ConicGradient {
+        from: Angle,
+        stops: Vec<ColorStop>,
+    },
+    Image(BackgroundImage),
+}
+
+impl Background {
+    pub(crate) fn add_files<W: Write>(&self, emitter: &mut PresentationEmitter<W>) -> Result<()> {
+        match self {
+            Background::Image(image) => image.source.add_files(emitter),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Display for Background {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn join(stops: &[ColorStop]) -> String {
+            stops
+                .iter()
+                .map(ColorStop::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
         match self {
             Background::Unspecified => write!(f, "unset"),
             Background::Color(color) => write!(f, "{color}"),
+            Background::LinearGradient { angle, stops } => {
+                write!(f, "linear-gradient({angle}, {})", join(stops))
+            }
+            Background::RadialGradient { stops } => write!(f, "radial-gradient({})", join(stops)),
+            Background::ConicGradient { from, stops } => {
+                write!(f, "conic-gradient(from {from}, {})", join(stops))
+            }
+            Background::Image(image) => write!(f, "{image}"),
+        }
+    }
+}
+
+/// A color of a gradient. Stops without a position are spread evenly
+/// between their neighbours.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ColorStop {
+    pub color: Color,
+    pub position: Option<StyleUnit>,
+}
+
+impl From<Color> for ColorStop {
+    fn from(color: Color) -> Self {
+        Self {
+            color,
+            position: None,
         }
     }
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+impl Display for ColorStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.position {
+            Some(position) => write!(f, "{} {position}", self.color),
+            None => write!(f, "{}", self.color),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BackgroundImage {
+    pub source: ImageSource,
+    pub size: BackgroundSize,
+    pub x: StyleUnit,
+    pub y: StyleUnit,
+    pub repeat: bool,
+}
+
+impl BackgroundImage {
+    /// Creates an image covering the whole background, centered and not
+    /// repeated.
+    pub fn new(source: ImageSource) -> Self {
+        Self {
+            source,
+            size: BackgroundSize::Cover,
+            x: StyleUnit::Percent(50.0),
+            y: StyleUnit::Percent(50.0),
+            repeat: false,
+        }
+    }
+}
+
+impl Display for BackgroundImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "url(\"{}\") {} {} / {} {}",
+            self.source,
+            self.x,
+            self.y,
+            self.size,
+            if self.repeat { "repeat" } else { "no-repeat" }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, strum::Display, strum::EnumString)]
+pub enum BackgroundSize {
+    #[strum(serialize = "Cover", to_string = "cover")]
+    Cover,
+    #[strum(serialize = "Contain", to_string = "contain")]
+    Contain,
+    #[strum(to_string = "{0}")]
+    Fixed(StyleUnit),
+}
+
+/// One side of a [`Border`]. Sides with an unspecified width are not drawn.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BorderSide {
+    pub width: StyleUnit,
+    pub color: Color,
+}
+
+impl Display for BorderSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} solid {}", self.width, self.color)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Border {
+    pub left: BorderSide,
+    pub top: BorderSide,
+    pub right: BorderSide,
+    pub bottom: BorderSide,
+}
+
+impl Border {
+    pub fn all(side: BorderSide) -> Border {
+        Self {
+            left: side.clone(),
+            top: side.clone(),
+            right: side.clone(),
+            bottom: side,
+        }
+    }
+
+    /// Overrides every side, which is specified in `other`.
+    pub fn merge(&mut self, other: &Border) {
+        for (side, other) in [
+            (&mut self.left, &other.left),
+            (&mut self.top, &other.top),
+            (&mut self.right, &other.right),
+            (&mut self.bottom, &other.bottom),
+        ] {
+            if other.width != StyleUnit::Unspecified {
+                *side = other.clone();
+            }
+        }
+    }
+
+    fn write_css(&self, result: &mut String) {
+        use std::fmt::Write;
+        if self.left == self.top && self.top == self.right && self.right == self.bottom {
+            if self.top.width != StyleUnit::Unspecified {
+                writeln!(result, "    border: {};", self.top).expect("infallible");
+            }
+            return;
+        }
+        for (name, side) in [
+            ("left", &self.left),
+            ("top", &self.top),
+            ("right", &self.right),
+            ("bottom", &self.bottom),
+        ] {
+            if side.width != StyleUnit::Unspecified {
+                writeln!(result, "    border-{name}: {side};").expect("infallible");
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BoxShadow {
+    pub x: StyleUnit,
+    pub y: StyleUnit,
+    pub blur: StyleUnit,
+    pub color: Color,
+}
+
+impl Display for BoxShadow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.x, self.y, self.blur, self.color)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct Color {
     r: u8,
     g: u8,
     b: u8,
     alpha: u8,
+    /// The token this color was declared as, which is emitted instead of the
+    /// color itself.
+    token: Option<TokenName>,
 }
 
 impl Color {
@@ -1022,18 +2087,41 @@ impl Color {
             g,
             b,
             alpha: 0xff,
+            token: None,
         }
     }
 
     pub const fn argb(r: u8, g: u8, b: u8, alpha: u8) -> Self {
-        Self { r, g, b, alpha }
+        Self {
+            r,
+            g,
+            b,
+            alpha,
+            token: None,
+        }
+    }
+
+    pub fn with_token(self, token: TokenName) -> Self {
+        Self {
+            token: Some(token),
+            ..self
+        }
+    }
+
+    /// The color itself without the token it was declared as, for places
+    /// which can not refer to css custom properties.
+    pub fn without_token(self) -> Self {
+        Self {
+            token: None,
+            ..self
+        }
     }
 
     pub fn from_css(color: &str) -> Self {
         csscolorparser::parse(color)
             .map(|color| {
                 let [r, g, b, alpha] = color.to_rgba8();
-                Color { r, g, b, alpha }
+                Color::argb(r, g, b, alpha)
             })
             .unwrap_or_default()
     }
@@ -1041,6 +2129,9 @@ impl Color {
 
 impl Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(token) = &self.token {
+            return write!(f, "{token}");
+        }
         write!(
             f,
             "rgb({}, {}, {}, {})",
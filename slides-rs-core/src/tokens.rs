@@ -0,0 +1,39 @@
+use std::{fmt::Display, sync::Arc};
+
+use crate::{Color, Font, StyleUnit};
+
+/// The name of a design token. Values referring to a token are emitted as
+/// the css custom property of the token, so that its value is only defined
+/// once in the css and can be changed at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenName(Arc<str>);
+
+impl TokenName {
+    pub fn new(name: &str) -> Self {
+        Self(name.into())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    pub fn css_property(&self) -> String {
+        format!("--{}", self.name())
+    }
+}
+
+impl Display for TokenName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "var({})", self.css_property())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, strum::Display)]
+pub enum TokenValue {
+    #[strum(to_string = "{0}")]
+    Color(Color),
+    #[strum(to_string = "{0}")]
+    StyleUnit(StyleUnit),
+    #[strum(to_string = "{0}")]
+    Font(Font),
+}